@@ -1,9 +1,11 @@
 use crate::subscription::book::OrderBookEvent;
+use barter_instrument::Side;
 use chrono::{DateTime, Utc};
 use derive_more::Display;
+use itertools::Itertools;
 use rust_decimal::Decimal;
 use serde::{Deserialize, Serialize};
-use std::cmp::Ordering;
+use std::{cmp::Ordering, collections::BTreeMap};
 use tracing::debug;
 
 /// Provides a [`OrderBookL2Manager`](manager::OrderBookL2Manager) for maintaining a set of local
@@ -45,6 +47,34 @@ impl OrderBook {
         }
     }
 
+    /// Construct a consolidated [`OrderBook`] by aggregating the levels of many [`OrderBook`]s
+    /// (eg/ the same instrument across several exchanges), summing the amounts of levels with
+    /// an equal price.
+    ///
+    /// Sequence numbers are not comparable across [`OrderBook`]s, so the consolidated sequence is
+    /// zero, and the consolidated engine time is the latest of the provided [`OrderBook`]s.
+    pub fn consolidate<'a, Iter>(books: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = &'a OrderBook>,
+    {
+        let mut time_engine = None;
+        let mut bids = BTreeMap::<Decimal, Decimal>::new();
+        let mut asks = BTreeMap::<Decimal, Decimal>::new();
+
+        for book in books {
+            time_engine = time_engine.max(book.time_engine);
+
+            for level in book.bids.levels() {
+                *bids.entry(level.price).or_default() += level.amount;
+            }
+            for level in book.asks.levels() {
+                *asks.entry(level.price).or_default() += level.amount;
+            }
+        }
+
+        Self::new(0, time_engine, bids, asks)
+    }
+
     /// Current `u64` sequence number associated with the [`OrderBook`].
     pub fn sequence(&self) -> u64 {
         self.sequence
@@ -126,22 +156,169 @@ impl OrderBook {
             (None, None) => None,
         }
     }
+
+    /// Estimate the [`PriceImpact`] of a market order with the provided quote `notional`
+    /// (eg/ 1,000,000 USDT), relative to the current mid-price.
+    ///
+    /// A [`Side::Buy`] market order consumes the asks, and a [`Side::Sell`] market order
+    /// consumes the bids. The mid-price is calculated from the best [`Level`]s with a positive
+    /// price & amount, consistent with the [`Level`]s that can be consumed.
+    ///
+    /// Returns `None` if there is no mid-price, or if the consumed side has insufficient depth
+    /// to fill the `notional`.
+    pub fn price_impact(&self, side: Side, notional: Decimal) -> Option<PriceImpact> {
+        self.price_impacts(side, &[notional]).pop().flatten()
+    }
+
+    /// Estimate the [`PriceImpact`] of market orders for each of the provided quote `notionals`
+    /// (eg/ 100k, 1M & 5M USDT), relative to the current mid-price.
+    ///
+    /// The consumed side is walked once for all `notionals`, and the returned estimates are in
+    /// the same order as the provided `notionals`. See [`OrderBook::price_impact`].
+    ///
+    /// Note that this is `O(levels)` per call, since the cumulative notional of each side is not
+    /// cached between [`OrderBook`] updates.
+    pub fn price_impacts(&self, side: Side, notionals: &[Decimal]) -> Vec<Option<PriceImpact>> {
+        let best_fillable_price = |levels: &[Level]| {
+            levels
+                .iter()
+                .find(|level| is_fillable(level))
+                .map(|level| level.price)
+        };
+
+        let mid_price = match (
+            best_fillable_price(self.bids.levels()),
+            best_fillable_price(self.asks.levels()),
+        ) {
+            (Some(best_bid), Some(best_ask)) => mid_price(best_bid, best_ask),
+            (Some(best_price), None) | (None, Some(best_price)) => best_price,
+            (None, None) => return vec![None; notionals.len()],
+        };
+
+        match side {
+            Side::Buy => price_impacts(mid_price, self.asks.levels(), notionals),
+            Side::Sell => price_impacts(mid_price, self.bids.levels(), notionals),
+        }
+    }
+}
+
+/// Estimated impact of a market order walking the [`Level`]s of an [`OrderBook`].
+///
+/// See [`OrderBook::price_impact`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+pub struct PriceImpact {
+    /// Volume weighted average fill price of the market order.
+    pub average_price: Decimal,
+    /// Price of the last (worst) [`Level`] consumed by the market order.
+    pub worst_price: Decimal,
+    /// Basis points between the reference price and the `average_price` (ie/ slippage).
+    pub average_price_bps: Decimal,
+    /// Basis points between the reference price and the `worst_price` (ie/ how far the market
+    /// order moves the book).
+    pub worst_price_bps: Decimal,
+}
+
+/// Estimate the [`PriceImpact`] of a market order with the provided quote `notional` consuming
+/// the provided sorted [`Level`]s (best first), relative to the `reference_price`.
+///
+/// Returns `None` if the `notional` or `reference_price` is not positive, or if the [`Level`]s
+/// have insufficient depth to fill the `notional`.
+pub fn price_impact(
+    reference_price: Decimal,
+    levels: &[Level],
+    notional: Decimal,
+) -> Option<PriceImpact> {
+    price_impacts(reference_price, levels, &[notional])
+        .pop()
+        .flatten()
+}
+
+/// Estimate the [`PriceImpact`] of market orders for each of the provided quote `notionals`
+/// consuming the provided sorted [`Level`]s (best first), relative to the `reference_price`.
+///
+/// The [`Level`]s are walked once, filling the `notionals` in ascending order. [`Level`]s with a
+/// non-positive price or amount are skipped, since they cannot fill any notional.
+///
+/// Each estimate is `None` if its `notional` or the `reference_price` is not positive, or if the
+/// [`Level`]s have insufficient depth to fill its `notional`.
+pub fn price_impacts(
+    reference_price: Decimal,
+    levels: &[Level],
+    notionals: &[Decimal],
+) -> Vec<Option<PriceImpact>> {
+    let mut impacts = vec![None; notionals.len()];
+    if reference_price <= Decimal::ZERO {
+        return impacts;
+    }
+
+    let bps =
+        |price: Decimal| (price - reference_price).abs() / reference_price * Decimal::from(10_000);
+
+    let mut pending = notionals
+        .iter()
+        .copied()
+        .enumerate()
+        .filter(|(_, notional)| *notional > Decimal::ZERO)
+        .sorted_by_key(|(_, notional)| *notional)
+        .peekable();
+
+    let mut filled_notional = Decimal::ZERO;
+    let mut filled_amount = Decimal::ZERO;
+
+    let levels = levels.iter().filter(|level| is_fillable(level));
+
+    for level in levels {
+        let level_notional = level.price * level.amount;
+
+        while let Some((index, notional)) =
+            pending.next_if(|(_, notional)| *notional <= filled_notional + level_notional)
+        {
+            let amount = filled_amount + (notional - filled_notional) / level.price;
+            let average_price = notional / amount;
+
+            impacts[index] = Some(PriceImpact {
+                average_price,
+                worst_price: level.price,
+                average_price_bps: bps(average_price),
+                worst_price_bps: bps(level.price),
+            });
+        }
+
+        if pending.peek().is_none() {
+            break;
+        }
+
+        filled_notional += level_notional;
+        filled_amount += level.amount;
+    }
+
+    impacts
+}
+
+/// Determine if a market order can consume the provided [`Level`] (ie/ it has a positive price &
+/// amount).
+fn is_fillable(level: &Level) -> bool {
+    level.price > Decimal::ZERO && level.amount > Decimal::ZERO
 }
 
 /// Normalised Barter [`Level`]s for one `Side` of the [`OrderBook`].
 #[derive(Clone, PartialEq, Eq, Debug, Deserialize, Serialize)]
 pub struct OrderBookSide<Side> {
-    #[serde(skip_serializing)]
+    #[serde(skip_serializing, default)]
     pub side: Side,
     levels: Vec<Level>,
 }
 
 /// Unit type to tag an [`OrderBookSide`] as the bid Side (ie/ buyers) of an [`OrderBook`].
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Display)]
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Display,
+)]
 pub struct Bids;
 
 /// Unit type to tag an [`OrderBookSide`] as the ask Side (ie/ sellers) of an [`OrderBook`].
-#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Display)]
+#[derive(
+    Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Deserialize, Display,
+)]
 pub struct Asks;
 
 impl OrderBookSide<Bids> {
@@ -179,7 +356,7 @@ impl OrderBookSide<Asks> {
         L: Into<Level>,
     {
         let mut levels = levels.into_iter().map(L::into).collect::<Vec<_>>();
-        levels.sort_unstable_by(|a, b| a.price.cmp(&b.price));
+        levels.sort_unstable_by_key(|level| level.price);
 
         Self { side: Asks, levels }
     }
@@ -189,7 +366,7 @@ impl OrderBookSide<Asks> {
     where
         L: Into<Level> + Copy,
     {
-        levels.into_iter().for_each(|upsert| {
+        levels.iter().for_each(|upsert| {
             let upsert = (*upsert).into();
             self.upsert_single(upsert, |existing| existing.price.cmp(&upsert.price))
         })
@@ -606,6 +783,224 @@ mod tests {
                 )
             }
         }
+
+        #[test]
+        fn test_consolidate() {
+            let time_earliest = DateTime::<Utc>::MIN_UTC;
+            let time_latest = DateTime::<Utc>::MAX_UTC;
+
+            let books = [
+                OrderBook::new(
+                    10,
+                    Some(time_earliest),
+                    vec![Level::new(dec!(99), dec!(1)), Level::new(dec!(98), dec!(2))],
+                    vec![Level::new(dec!(101), dec!(1))],
+                ),
+                OrderBook::new(
+                    20,
+                    Some(time_latest),
+                    vec![Level::new(dec!(99), dec!(3))],
+                    vec![
+                        Level::new(dec!(101), dec!(2)),
+                        Level::new(dec!(102), dec!(1)),
+                    ],
+                ),
+            ];
+
+            assert_eq!(
+                OrderBook::consolidate(&books),
+                OrderBook::new(
+                    0,
+                    Some(time_latest),
+                    vec![Level::new(dec!(99), dec!(4)), Level::new(dec!(98), dec!(2))],
+                    vec![
+                        Level::new(dec!(101), dec!(3)),
+                        Level::new(dec!(102), dec!(1))
+                    ],
+                )
+            );
+        }
+
+        #[test]
+        fn test_price_impact() {
+            struct TestCase {
+                input_book: OrderBook,
+                input_side: Side,
+                input_notional: Decimal,
+                expected: Option<PriceImpact>,
+            }
+
+            // Mid-price of 100
+            let book = OrderBook::new(
+                0,
+                None,
+                vec![
+                    Level::new(dec!(99), dec!(10)),
+                    Level::new(dec!(98), dec!(10)),
+                ],
+                vec![
+                    Level::new(dec!(101), dec!(10)),
+                    Level::new(dec!(103), dec!(30)),
+                ],
+            );
+
+            let tests = vec![
+                TestCase {
+                    // TC0: buy fully consumes best ask level only
+                    input_book: book.clone(),
+                    input_side: Side::Buy,
+                    input_notional: dec!(1010),
+                    expected: Some(PriceImpact {
+                        average_price: dec!(101),
+                        worst_price: dec!(101),
+                        average_price_bps: dec!(100),
+                        worst_price_bps: dec!(100),
+                    }),
+                },
+                TestCase {
+                    // TC1: buy partially consumes second ask level
+                    input_book: book.clone(),
+                    input_side: Side::Buy,
+                    input_notional: dec!(2040),
+                    expected: Some(PriceImpact {
+                        average_price: dec!(102),
+                        worst_price: dec!(103),
+                        average_price_bps: dec!(200),
+                        worst_price_bps: dec!(300),
+                    }),
+                },
+                TestCase {
+                    // TC2: sell fully consumes all bid levels
+                    input_book: book.clone(),
+                    input_side: Side::Sell,
+                    input_notional: dec!(1970),
+                    expected: Some(PriceImpact {
+                        average_price: dec!(98.5),
+                        worst_price: dec!(98),
+                        average_price_bps: dec!(150),
+                        worst_price_bps: dec!(200),
+                    }),
+                },
+                TestCase {
+                    // TC3: insufficient bid depth to fill notional
+                    input_book: book.clone(),
+                    input_side: Side::Sell,
+                    input_notional: dec!(1971),
+                    expected: None,
+                },
+                TestCase {
+                    // TC4: non-positive notional
+                    input_book: book,
+                    input_side: Side::Buy,
+                    input_notional: dec!(0),
+                    expected: None,
+                },
+                TestCase {
+                    // TC5: empty book has no mid-price
+                    input_book: OrderBook::default(),
+                    input_side: Side::Buy,
+                    input_notional: dec!(1000),
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = test
+                    .input_book
+                    .price_impact(test.input_side, test.input_notional);
+                assert_eq!(actual, test.expected, "TC{index} failed");
+            }
+        }
+
+        #[test]
+        fn test_price_impact_skips_invalid_levels() {
+            let levels = [
+                Level::new(dec!(0), dec!(1000)),
+                Level::new(dec!(101), dec!(0)),
+                Level::new(dec!(101), dec!(10)),
+            ];
+
+            let actual = price_impact(dec!(100), &levels, dec!(1010));
+
+            let expected = Some(PriceImpact {
+                average_price: dec!(101),
+                worst_price: dec!(101),
+                average_price_bps: dec!(100),
+                worst_price_bps: dec!(100),
+            });
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn test_price_impact_mid_price_skips_invalid_levels() {
+            // Zero price best ask & zero amount best bid are ignored, so the mid-price is 100
+            let book = OrderBook::new(
+                0,
+                None,
+                vec![
+                    Level::new(dec!(99.5), dec!(0)),
+                    Level::new(dec!(99), dec!(10)),
+                ],
+                vec![
+                    Level::new(dec!(0), dec!(10)),
+                    Level::new(dec!(101), dec!(10)),
+                ],
+            );
+
+            let actual = book.price_impact(Side::Buy, dec!(1010));
+
+            let expected = Some(PriceImpact {
+                average_price: dec!(101),
+                worst_price: dec!(101),
+                average_price_bps: dec!(100),
+                worst_price_bps: dec!(100),
+            });
+
+            assert_eq!(actual, expected);
+        }
+
+        #[test]
+        fn test_price_impacts() {
+            // Mid-price of 100
+            let book = OrderBook::new(
+                0,
+                None,
+                vec![Level::new(dec!(99), dec!(10))],
+                vec![
+                    Level::new(dec!(101), dec!(10)),
+                    Level::new(dec!(103), dec!(30)),
+                ],
+            );
+
+            // Unordered notionals, including non-positive & insufficient depth notionals
+            let actual = book.price_impacts(
+                Side::Buy,
+                &[dec!(2040), dec!(0), dec!(1010), dec!(5000), dec!(1010)],
+            );
+
+            let best_level = Some(PriceImpact {
+                average_price: dec!(101),
+                worst_price: dec!(101),
+                average_price_bps: dec!(100),
+                worst_price_bps: dec!(100),
+            });
+
+            let expected = vec![
+                Some(PriceImpact {
+                    average_price: dec!(102),
+                    worst_price: dec!(103),
+                    average_price_bps: dec!(200),
+                    worst_price_bps: dec!(300),
+                }),
+                None,
+                best_level,
+                None,
+                best_level,
+            ];
+
+            assert_eq!(actual, expected);
+        }
     }
 
     mod order_book_side {
@@ -22,6 +22,12 @@ pub enum DataError {
     #[error("initial snapshot invalid: {0}")]
     InitialSnapshotInvalid(String),
 
+    #[error("contract specification missing for: {0}")]
+    ContractSpecMissing(SubscriptionId),
+
+    #[error("contract amount cannot be converted into a base asset amount for: {0}")]
+    ContractAmountInvalid(SubscriptionId),
+
     #[error("SocketError: {0}")]
     Socket(String),
 
@@ -31,6 +37,14 @@ pub enum DataError {
         sub_kind: SubKind,
     },
 
+    #[error(
+        "DynamicStreams was not initialised with a Stream for exchange: {exchange}, kind: {sub_kind}"
+    )]
+    DynamicStreamMissing {
+        exchange: ExchangeId,
+        sub_kind: SubKind,
+    },
+
     #[error(
         "\
         InvalidSequence: first_update_id {first_update_id} does not follow on from the \
@@ -220,3 +220,119 @@ impl<InstrumentKey> From<MarketEvent<InstrumentKey, Liquidation>>
         value.map_kind(Liquidation::into)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::{Level, OrderBook};
+    use barter_instrument::Side;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    /// Generate an example of every [`DataKind`] variant.
+    ///
+    /// The exhaustive match fails to compile when a new [`DataKind`] variant is added, acting as a
+    /// reminder to add an example so the new variant is covered by the tests below.
+    fn data_kind_examples() -> Vec<DataKind> {
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1_700_000_000_000));
+        let book = OrderBook::new(
+            10,
+            Some(time),
+            vec![Level::new(dec!(99.5), dec!(1.0))],
+            vec![Level::new(dec!(100.5), dec!(2.0))],
+        );
+
+        let examples = vec![
+            DataKind::Trade(PublicTrade {
+                id: "trade_id".to_string(),
+                price: 100.0,
+                amount: 1.5,
+                side: Side::Buy,
+            }),
+            DataKind::OrderBookL1(OrderBookL1 {
+                last_update_time: time,
+                best_bid: Some(Level::new(dec!(99.5), dec!(1.0))),
+                best_ask: None,
+            }),
+            DataKind::OrderBook(OrderBookEvent::Snapshot(book.clone())),
+            DataKind::OrderBook(OrderBookEvent::Update(book)),
+            DataKind::Candle(Candle {
+                close_time: time,
+                open: 100.0,
+                high: 101.0,
+                low: 99.0,
+                close: 100.5,
+                volume: 10.0,
+                trade_count: 5,
+            }),
+            DataKind::Liquidation(Liquidation {
+                side: Side::Sell,
+                price: 98.0,
+                quantity: 3.0,
+                time,
+            }),
+        ];
+
+        for example in &examples {
+            match example {
+                DataKind::Trade(_)
+                | DataKind::OrderBookL1(_)
+                | DataKind::OrderBook(_)
+                | DataKind::Candle(_)
+                | DataKind::Liquidation(_) => {}
+            }
+        }
+
+        examples
+    }
+
+    #[test]
+    fn test_data_kind_serde_round_trip() {
+        for (index, example) in data_kind_examples().into_iter().enumerate() {
+            let serialised = serde_json::to_string(&example).unwrap();
+            let actual = serde_json::from_str::<DataKind>(&serialised).unwrap();
+            assert_eq!(actual, example, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_data_kind_serde_is_tagged_by_variant() {
+        let actual = data_kind_examples()
+            .iter()
+            .map(|example| {
+                let serialised = serde_json::to_value(example).unwrap();
+                serialised
+                    .as_object()
+                    .unwrap()
+                    .keys()
+                    .next()
+                    .unwrap()
+                    .clone()
+            })
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                "Trade",
+                "OrderBookL1",
+                "OrderBook",
+                "OrderBook",
+                "Candle",
+                "Liquidation"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_data_kind_kind_name() {
+        let examples = data_kind_examples();
+        let actual = examples.iter().map(DataKind::kind_name).collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec!["public_trade", "l1", "l2", "l2", "candle", "liquidation"]
+        );
+    }
+}
@@ -542,20 +542,7 @@ mod tests {
 
         for (index, test) in tests.into_iter().enumerate() {
             let actual = test.updater.validate_first_update(&test.input);
-            match (actual, test.expected) {
-                (Ok(actual), Ok(expected)) => {
-                    assert_eq!(actual, expected, "TC{} failed", index)
-                }
-                (Err(_), Err(_)) => {
-                    // Test passed
-                }
-                (actual, expected) => {
-                    // Test failed
-                    panic!(
-                        "TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n"
-                    );
-                }
-            }
+            assert_eq!(actual, test.expected, "TC{index} failed");
         }
     }
 
@@ -611,20 +598,7 @@ mod tests {
 
         for (index, test) in tests.into_iter().enumerate() {
             let actual = test.updater.validate_next_update(&test.input);
-            match (actual, test.expected) {
-                (Ok(actual), Ok(expected)) => {
-                    assert_eq!(actual, expected, "TC{} failed", index)
-                }
-                (Err(_), Err(_)) => {
-                    // Test passed
-                }
-                (actual, expected) => {
-                    // Test failed
-                    panic!(
-                        "TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n"
-                    );
-                }
-            }
+            assert_eq!(actual, test.expected, "TC{index} failed");
         }
     }
 
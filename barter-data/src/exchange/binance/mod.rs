@@ -4,7 +4,7 @@ use self::{
 };
 use crate::{
     ExchangeWsStream, NoInitialSnapshots,
-    exchange::{Connector, ExchangeServer, ExchangeSub, StreamSelector},
+    exchange::{Connector, ExchangeServer, ExchangeSub, StreamSelector, SubscribeRateLimit},
     instrument::InstrumentData,
     subscriber::{WebSocketSubscriber, validator::WebSocketSubValidator},
     subscription::{Map, book::OrderBooksL1, trade::PublicTrades},
@@ -15,7 +15,7 @@ use barter_integration::{
     error::SocketError,
     protocol::websocket::{WebSocketSerdeParser, WsMessage},
 };
-use std::{fmt::Debug, marker::PhantomData};
+use std::{fmt::Debug, marker::PhantomData, num::NonZeroUsize, time::Duration};
 use url::Url;
 
 /// OrderBook types common to both [`BinanceSpot`](spot::BinanceSpot) and
@@ -47,6 +47,21 @@ pub mod subscription;
 /// [`BinanceFuturesUsd`](futures::BinanceFuturesUsd).
 pub mod trade;
 
+/// Maximum number of streams [`Binance`] subscribes to in a single SUBSCRIBE request, larger
+/// `Subscription` batches are split over several requests.
+pub const MAX_STREAMS_PER_REQUEST_BINANCE: usize = 200;
+
+/// [`Binance`] [`SubscribeRateLimit`] of incoming WebSocket messages per connection.
+///
+/// BinanceSpot allows 5 messages per second, and BinanceFuturesUsd 10, so the stricter limit is
+/// used for both.
+///
+/// See docs: <https://developers.binance.com/docs/binance-spot-api-docs/web-socket-streams#websocket-limits>
+pub const SUBSCRIBE_RATE_LIMIT_BINANCE: SubscribeRateLimit = SubscribeRateLimit {
+    max_messages: NonZeroUsize::new(5).unwrap(),
+    interval: Duration::from_secs(1),
+};
+
 /// Convenient type alias for a Binance [`ExchangeWsStream`] using [`WebSocketSerdeParser`].
 pub type BinanceWsStream<Transformer> = ExchangeWsStream<WebSocketSerdeParser, Transformer>;
 
@@ -75,6 +90,10 @@ where
         Url::parse(Server::websocket_url()).map_err(SocketError::UrlParse)
     }
 
+    fn subscribe_rate_limit() -> Option<SubscribeRateLimit> {
+        Some(SUBSCRIBE_RATE_LIMIT_BINANCE)
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
         let stream_names = exchange_subs
             .into_iter()
@@ -90,18 +109,25 @@ where
             })
             .collect::<Vec<String>>();
 
-        vec![WsMessage::text(
-            serde_json::json!({
-                "method": "SUBSCRIBE",
-                "params": stream_names,
-                "id": 1
+        stream_names
+            .chunks(MAX_STREAMS_PER_REQUEST_BINANCE)
+            .enumerate()
+            .map(|(index, stream_names)| {
+                WsMessage::text(
+                    serde_json::json!({
+                        "method": "SUBSCRIBE",
+                        "params": stream_names,
+                        "id": index + 1
+                    })
+                    .to_string(),
+                )
             })
-            .to_string(),
-        )]
+            .collect()
     }
 
-    fn expected_responses<InstrumentKey>(_: &Map<InstrumentKey>) -> usize {
-        1
+    fn expected_responses<InstrumentKey>(map: &Map<InstrumentKey>) -> usize {
+        // One response is received per SUBSCRIBE request
+        map.0.len().div_ceil(MAX_STREAMS_PER_REQUEST_BINANCE)
     }
 }
 
@@ -158,3 +184,37 @@ where
         serializer.serialize_str(Self::ID.as_str())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Identifier, exchange::binance::spot::BinanceSpot};
+
+    #[test]
+    fn test_binance_requests_are_split_into_batches() {
+        let exchange_subs = (0..MAX_STREAMS_PER_REQUEST_BINANCE + 1)
+            .map(|index| {
+                ExchangeSub::from((
+                    BinanceChannel::TRADES,
+                    BinanceMarket(format!("BASE{index}USDT").into()),
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let map = Map::<()>::from_iter(exchange_subs.iter().map(|sub| (sub.id(), ())));
+
+        let requests = BinanceSpot::requests(exchange_subs);
+        assert_eq!(requests.len(), 2);
+        assert_eq!(BinanceSpot::expected_responses(&map), 2);
+
+        let params = requests
+            .iter()
+            .map(|request| {
+                let request =
+                    serde_json::from_str::<serde_json::Value>(request.to_text().unwrap()).unwrap();
+                request["params"].as_array().unwrap().len()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(params, vec![MAX_STREAMS_PER_REQUEST_BINANCE, 1]);
+    }
+}
@@ -0,0 +1,243 @@
+use super::super::message::GateioMessage;
+use crate::{
+    Identifier,
+    books::Level,
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
+    subscription::book::OrderBookL1,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Gateio`](super::super::Gateio) real-time OrderBook Level1
+/// (top of books) WebSocket message.
+pub type GateioOrderBookL1 = GateioMessage<GateioOrderBookL1Inner>;
+
+/// [`Gateio`](super::super::Gateio) real-time best bid and ask, common to the spot and futures
+/// `book_ticker` channels.
+///
+/// Note that futures sizes are sent as numbers of contracts, whereas spot sizes are strings.
+///
+/// ### Raw Payload Examples
+/// #### Spot
+/// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#best-bid-or-ask-price>
+/// ```json
+/// {
+///   "t": 1606293275123,
+///   "u": 48733182,
+///   "s": "BTC_USDT",
+///   "b": "19177.79",
+///   "B": "0.0003341504",
+///   "a": "19179.38",
+///   "A": "0.09"
+/// }
+/// ```
+///
+/// #### Perpetual
+/// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#best-ask-bid-subscription>
+/// ```json
+/// {
+///   "t": 1615366379123,
+///   "u": 2517661076,
+///   "s": "BTC_USD",
+///   "b": "54696.6",
+///   "B": 37000,
+///   "a": "54696.7",
+///   "A": 47061
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct GateioOrderBookL1Inner {
+    #[serde(rename = "s")]
+    pub market: String,
+    #[serde(
+        rename = "t",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "u")]
+    pub update_id: u64,
+    #[serde(rename = "b")]
+    pub best_bid_price: Decimal,
+    #[serde(rename = "B")]
+    pub best_bid_amount: Decimal,
+    #[serde(rename = "a")]
+    pub best_ask_price: Decimal,
+    #[serde(rename = "A")]
+    pub best_ask_amount: Decimal,
+}
+
+impl Identifier<Option<SubscriptionId>> for GateioOrderBookL1 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((&self.channel, &self.data.market)).id())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, GateioOrderBookL1)>
+    for MarketIter<InstrumentKey, OrderBookL1>
+{
+    fn from(
+        (exchange_id, instrument, book): (ExchangeId, InstrumentKey, GateioOrderBookL1),
+    ) -> Self {
+        let book = book.data;
+
+        let best_bid = (!book.best_bid_price.is_zero())
+            .then(|| Level::new(book.best_bid_price, book.best_bid_amount));
+
+        let best_ask = (!book.best_ask_price.is_zero())
+            .then(|| Level::new(book.best_ask_price, book.best_ask_amount));
+
+        Self(vec![Ok(MarketEvent {
+            time_exchange: book.time,
+            time_received: Utc::now(),
+            exchange: exchange_id,
+            instrument,
+            kind: OrderBookL1 {
+                last_update_time: book.time,
+                best_bid,
+                best_ask,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_gateio_message_order_book_l1() {
+            struct TestCase {
+                input: &'static str,
+                expected: GateioOrderBookL1,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid spot GateioOrderBookL1
+                    input: r#"
+                    {
+                        "time": 1606293275,
+                        "time_ms": 1606293275723,
+                        "channel": "spot.book_ticker",
+                        "event": "update",
+                        "result": {
+                            "t": 1606293275123,
+                            "u": 48733182,
+                            "s": "BTC_USDT",
+                            "b": "19177.79",
+                            "B": "0.0003341504",
+                            "a": "19179.38",
+                            "A": "0.09"
+                        }
+                    }
+                    "#,
+                    expected: GateioOrderBookL1 {
+                        channel: "spot.book_ticker".to_string(),
+                        error: None,
+                        data: GateioOrderBookL1Inner {
+                            market: "BTC_USDT".to_string(),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1606293275123,
+                            )),
+                            update_id: 48733182,
+                            best_bid_price: dec!(19177.79),
+                            best_bid_amount: dec!(0.0003341504),
+                            best_ask_price: dec!(19179.38),
+                            best_ask_amount: dec!(0.09),
+                        },
+                    },
+                },
+                TestCase {
+                    // TC1: valid perpetual GateioOrderBookL1 with contract sizes
+                    input: r#"
+                    {
+                        "time": 1615366379,
+                        "time_ms": 1615366379123,
+                        "channel": "futures.book_ticker",
+                        "event": "update",
+                        "result": {
+                            "t": 1615366379123,
+                            "u": 2517661076,
+                            "s": "BTC_USD",
+                            "b": "54696.6",
+                            "B": 37000,
+                            "a": "54696.7",
+                            "A": 47061
+                        }
+                    }
+                    "#,
+                    expected: GateioOrderBookL1 {
+                        channel: "futures.book_ticker".to_string(),
+                        error: None,
+                        data: GateioOrderBookL1Inner {
+                            market: "BTC_USD".to_string(),
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1615366379123,
+                            )),
+                            update_id: 2517661076,
+                            best_bid_price: dec!(54696.6),
+                            best_bid_amount: dec!(37000),
+                            best_ask_price: dec!(54696.7),
+                            best_ask_amount: dec!(47061),
+                        },
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<GateioOrderBookL1>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gateio_order_book_l1_to_market_iter() {
+        let book = GateioOrderBookL1 {
+            channel: "spot.book_ticker".to_string(),
+            error: None,
+            data: GateioOrderBookL1Inner {
+                market: "BTC_USDT".to_string(),
+                time: datetime_utc_from_epoch_duration(Duration::from_millis(1606293275123)),
+                update_id: 48733182,
+                best_bid_price: dec!(19177.79),
+                best_bid_amount: dec!(0.0003341504),
+                best_ask_price: dec!(0),
+                best_ask_amount: dec!(0),
+            },
+        };
+
+        assert_eq!(
+            book.id(),
+            Some(SubscriptionId::from("spot.book_ticker|BTC_USDT"))
+        );
+
+        let actual =
+            MarketIter::<&str, OrderBookL1>::from((ExchangeId::GateioSpot, "btc_usdt", book))
+                .0
+                .into_iter()
+                .map(|event| event.unwrap().kind)
+                .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![OrderBookL1 {
+                last_update_time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                    1606293275123
+                )),
+                best_bid: Some(Level::new(dec!(19177.79), dec!(0.0003341504))),
+                best_ask: None,
+            }]
+        );
+    }
+}
@@ -0,0 +1,2 @@
+/// Level 1 OrderBook types (top of books).
+pub mod l1;
@@ -1,7 +1,11 @@
+use super::{
+    perpetual::{GateioPerpetualsBtc, GateioPerpetualsUsd},
+    spot::GateioSpot,
+};
 use crate::{
     Identifier,
     instrument::InstrumentData,
-    subscription::{Subscription, trade::PublicTrades},
+    subscription::{Subscription, book::OrderBooksL1, trade::PublicTrades},
 };
 use barter_instrument::instrument::market_data::kind::MarketDataInstrumentKind;
 use serde::Serialize;
@@ -29,6 +33,18 @@ impl GateioChannel {
     ///
     /// See docs: <https://www.gate.io/docs/developers/options/ws/en/#public-contract-trades-channel>
     pub const OPTION_TRADES: Self = Self("options.trades");
+
+    /// Gateio [`MarketDataInstrumentKind::Spot`] real-time OrderBook Level1 (top of books) channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/apiv4/ws/en/#best-bid-or-ask-price>
+    pub const SPOT_ORDER_BOOK_L1: Self = Self("spot.book_ticker");
+
+    /// Gateio [`MarketDataInstrumentKind::Future`] & [`MarketDataInstrumentKind::Perpetual`]
+    /// real-time OrderBook Level1 (top of books) channel.
+    ///
+    /// See docs: <https://www.gate.io/docs/developers/futures/ws/en/#best-ask-bid-subscription>
+    /// See docs: <https://www.gate.io/docs/developers/delivery/ws/en/#best-ask-bid-subscription>
+    pub const FUTURE_ORDER_BOOK_L1: Self = Self("futures.book_ticker");
 }
 
 impl<GateioExchange, Instrument> Identifier<GateioChannel>
@@ -47,6 +63,28 @@ where
     }
 }
 
+impl<Instrument> Identifier<GateioChannel> for Subscription<GateioSpot, Instrument, OrderBooksL1> {
+    fn id(&self) -> GateioChannel {
+        GateioChannel::SPOT_ORDER_BOOK_L1
+    }
+}
+
+impl<Instrument> Identifier<GateioChannel>
+    for Subscription<GateioPerpetualsUsd, Instrument, OrderBooksL1>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_ORDER_BOOK_L1
+    }
+}
+
+impl<Instrument> Identifier<GateioChannel>
+    for Subscription<GateioPerpetualsBtc, Instrument, OrderBooksL1>
+{
+    fn id(&self) -> GateioChannel {
+        GateioChannel::FUTURE_ORDER_BOOK_L1
+    }
+}
+
 impl AsRef<str> for GateioChannel {
     fn as_ref(&self) -> &str {
         self.0
@@ -13,6 +13,11 @@ use serde_json::json;
 use std::{fmt::Debug, marker::PhantomData};
 use url::Url;
 
+/// OrderBook types common to [`GateioSpot`](spot::GateioSpot),
+/// [`GateioPerpetualUsdt`](perpetual::GateioPerpetualsUsd) and
+/// [`GateioPerpetualBtc`](perpetual::GateioPerpetualsBtc).
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
@@ -2,9 +2,12 @@ use self::trade::GateioFuturesTrades;
 use super::Gateio;
 use crate::{
     NoInitialSnapshots,
-    exchange::{ExchangeServer, StreamSelector, gateio::GateiotWsStream},
+    exchange::{
+        ExchangeServer, StreamSelector,
+        gateio::{GateiotWsStream, book::l1::GateioOrderBookL1},
+    },
     instrument::InstrumentData,
-    subscription::trade::PublicTrades,
+    subscription::{book::OrderBooksL1, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
 };
 use barter_instrument::exchange::ExchangeId;
@@ -43,6 +46,21 @@ where
     >;
 }
 
+/// Note that [`OrderBooksL1`] amounts are denominated in contracts, not in the base asset, since
+/// Gateio futures `book_ticker` sizes are sent as numbers of contracts. Multiply by the contract
+/// `quanto_multiplier` to convert them into base asset amounts.
+///
+/// See docs: <https://www.gate.io/docs/developers/apiv4/en/#get-a-single-contract>
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for GateioPerpetualsUsd
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = GateiotWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL1, GateioOrderBookL1>,
+    >;
+}
+
 impl Display for GateioPerpetualsUsd {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "GateioPerpetualsUsd")
@@ -79,6 +97,18 @@ where
     >;
 }
 
+/// Note that [`OrderBooksL1`] amounts are denominated in contracts, as for
+/// [`GateioPerpetualsUsd`].
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for GateioPerpetualsBtc
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = GateiotWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL1, GateioOrderBookL1>,
+    >;
+}
+
 impl Display for GateioPerpetualsBtc {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "GateioPerpetualsBtc")
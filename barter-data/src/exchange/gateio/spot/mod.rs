@@ -2,9 +2,12 @@ use self::trade::GateioSpotTrade;
 use super::Gateio;
 use crate::{
     NoInitialSnapshots,
-    exchange::{ExchangeServer, StreamSelector, gateio::GateiotWsStream},
+    exchange::{
+        ExchangeServer, StreamSelector,
+        gateio::{GateiotWsStream, book::l1::GateioOrderBookL1},
+    },
     instrument::InstrumentData,
-    subscription::trade::PublicTrades,
+    subscription::{book::OrderBooksL1, trade::PublicTrades},
     transformer::stateless::StatelessTransformer,
 };
 use barter_instrument::exchange::ExchangeId;
@@ -45,6 +48,16 @@ where
         GateiotWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, GateioSpotTrade>>;
 }
 
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for GateioSpot
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = GateiotWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL1, GateioOrderBookL1>,
+    >;
+}
+
 impl Display for GateioSpot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "GateioSpot")
@@ -0,0 +1,206 @@
+use super::{super::channel::HyperliquidChannel, HyperliquidLevel};
+use crate::{
+    Identifier,
+    books::Level,
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeSub, hyperliquid::message::HyperliquidMessage},
+    subscription::book::OrderBookL1,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Hyperliquid`](super::super::Hyperliquid) real-time OrderBook Level1
+/// (best bid and offer) WebSocket message.
+pub type HyperliquidOrderBookL1 = HyperliquidMessage<HyperliquidOrderBookL1Inner>;
+
+/// [`Hyperliquid`](super::super::Hyperliquid) real-time best bid and offer.
+///
+/// The "bbo" field contains the `[bid, ask]` levels, either of which is null if that side of the
+/// book is empty.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+/// ```json
+/// {
+///     "channel": "bbo",
+///     "data": {
+///         "coin": "BTC",
+///         "time": 1751427259657,
+///         "bbo": [
+///             {"px": "106217.0", "sz": "0.001", "n": 1},
+///             {"px": "106233.0", "sz": "0.26739", "n": 3}
+///         ]
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct HyperliquidOrderBookL1Inner {
+    pub coin: String,
+    #[serde(deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    pub bbo: [Option<HyperliquidLevel>; 2],
+}
+
+impl Identifier<Option<SubscriptionId>> for HyperliquidOrderBookL1 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((HyperliquidChannel::ORDER_BOOK_L1, &self.data.coin)).id())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, HyperliquidOrderBookL1)>
+    for MarketIter<InstrumentKey, OrderBookL1>
+{
+    fn from(
+        (exchange, instrument, book): (ExchangeId, InstrumentKey, HyperliquidOrderBookL1),
+    ) -> Self {
+        let [best_bid, best_ask] = book.data.bbo;
+
+        Self(vec![Ok(MarketEvent {
+            time_exchange: book.data.time,
+            time_received: Utc::now(),
+            exchange,
+            instrument,
+            kind: OrderBookL1 {
+                last_update_time: book.data.time,
+                best_bid: best_bid.map(Level::from),
+                best_ask: best_ask.map(Level::from),
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_hyperliquid_order_book_l1() {
+            struct TestCase {
+                input: &'static str,
+                expected: HyperliquidOrderBookL1,
+            }
+
+            let time = datetime_utc_from_epoch_duration(Duration::from_millis(1751427259657));
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid HyperliquidOrderBookL1 with both sides
+                    input: r#"
+                    {
+                        "channel": "bbo",
+                        "data": {
+                            "coin": "BTC",
+                            "time": 1751427259657,
+                            "bbo": [
+                                {"px": "106217.0", "sz": "0.001", "n": 1},
+                                {"px": "106233.0", "sz": "0.26739", "n": 3}
+                            ]
+                        }
+                    }
+                    "#,
+                    expected: HyperliquidOrderBookL1 {
+                        channel: "bbo".to_string(),
+                        data: HyperliquidOrderBookL1Inner {
+                            coin: "BTC".to_string(),
+                            time,
+                            bbo: [
+                                Some(HyperliquidLevel {
+                                    price: dec!(106217.0),
+                                    amount: dec!(0.001),
+                                    orders: 1,
+                                }),
+                                Some(HyperliquidLevel {
+                                    price: dec!(106233.0),
+                                    amount: dec!(0.26739),
+                                    orders: 3,
+                                }),
+                            ],
+                        },
+                    },
+                },
+                TestCase {
+                    // TC1: valid HyperliquidOrderBookL1 with empty ask side
+                    input: r#"
+                    {
+                        "channel": "bbo",
+                        "data": {
+                            "coin": "BTC",
+                            "time": 1751427259657,
+                            "bbo": [{"px": "106217.0", "sz": "0.001", "n": 1}, null]
+                        }
+                    }
+                    "#,
+                    expected: HyperliquidOrderBookL1 {
+                        channel: "bbo".to_string(),
+                        data: HyperliquidOrderBookL1Inner {
+                            coin: "BTC".to_string(),
+                            time,
+                            bbo: [
+                                Some(HyperliquidLevel {
+                                    price: dec!(106217.0),
+                                    amount: dec!(0.001),
+                                    orders: 1,
+                                }),
+                                None,
+                            ],
+                        },
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<HyperliquidOrderBookL1>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hyperliquid_order_book_l1_to_market_iter() {
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1751427259657));
+        let input = HyperliquidOrderBookL1 {
+            channel: "bbo".to_string(),
+            data: HyperliquidOrderBookL1Inner {
+                coin: "BTC".to_string(),
+                time,
+                bbo: [
+                    None,
+                    Some(HyperliquidLevel {
+                        price: dec!(106233.0),
+                        amount: dec!(0.26739),
+                        orders: 3,
+                    }),
+                ],
+            },
+        };
+
+        assert_eq!(input.id(), Some(SubscriptionId::from("bbo|BTC")));
+
+        let actual = MarketIter::<&str, OrderBookL1>::from((
+            ExchangeId::Hyperliquid,
+            "btc_usdc_perp",
+            input,
+        ))
+        .0
+        .into_iter()
+        .map(|event| event.unwrap().kind)
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![OrderBookL1 {
+                last_update_time: time,
+                best_bid: None,
+                best_ask: Some(Level::new(dec!(106233.0), dec!(0.26739))),
+            }]
+        );
+    }
+}
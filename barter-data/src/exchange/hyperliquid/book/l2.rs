@@ -0,0 +1,198 @@
+use super::{super::channel::HyperliquidChannel, HyperliquidLevel};
+use crate::{
+    Identifier,
+    books::OrderBook,
+    event::{MarketEvent, MarketIter},
+    exchange::{ExchangeSub, hyperliquid::message::HyperliquidMessage},
+    subscription::book::OrderBookEvent,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Hyperliquid`](super::super::Hyperliquid) real-time OrderBook Level2
+/// WebSocket message.
+pub type HyperliquidOrderBookL2 = HyperliquidMessage<HyperliquidOrderBookL2Inner>;
+
+/// [`Hyperliquid`](super::super::Hyperliquid) real-time OrderBook Level2 snapshot.
+///
+/// Every message is a full snapshot of the top levels, with the "levels" field containing the
+/// `[bids, asks]` levels. Hyperliquid provides no sequence number, so the snapshot time (epoch
+/// milliseconds) is used as the [`OrderBook`] sequence.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+/// ```json
+/// {
+///     "channel": "l2Book",
+///     "data": {
+///         "coin": "BTC",
+///         "time": 1751427259657,
+///         "levels": [
+///             [
+///                 {"px": "106217.0", "sz": "0.001", "n": 1},
+///                 {"px": "106216.0", "sz": "0.5", "n": 2}
+///             ],
+///             [
+///                 {"px": "106233.0", "sz": "0.26739", "n": 3}
+///             ]
+///         ]
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct HyperliquidOrderBookL2Inner {
+    pub coin: String,
+    #[serde(deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+    pub levels: [Vec<HyperliquidLevel>; 2],
+}
+
+impl Identifier<Option<SubscriptionId>> for HyperliquidOrderBookL2 {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(ExchangeSub::from((HyperliquidChannel::ORDER_BOOK_L2, &self.data.coin)).id())
+    }
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, HyperliquidOrderBookL2)>
+    for MarketIter<InstrumentKey, OrderBookEvent>
+{
+    fn from(
+        (exchange, instrument, book): (ExchangeId, InstrumentKey, HyperliquidOrderBookL2),
+    ) -> Self {
+        let time = book.data.time;
+        let [bids, asks] = book.data.levels;
+
+        Self(vec![Ok(MarketEvent {
+            time_exchange: time,
+            time_received: Utc::now(),
+            exchange,
+            instrument,
+            kind: OrderBookEvent::Snapshot(OrderBook::new(
+                time.timestamp_millis() as u64,
+                Some(time),
+                bids,
+                asks,
+            )),
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::books::Level;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_hyperliquid_order_book_l2() {
+            let input = r#"
+            {
+                "channel": "l2Book",
+                "data": {
+                    "coin": "BTC",
+                    "time": 1751427259657,
+                    "levels": [
+                        [
+                            {"px": "106217.0", "sz": "0.001", "n": 1},
+                            {"px": "106216.0", "sz": "0.5", "n": 2}
+                        ],
+                        [
+                            {"px": "106233.0", "sz": "0.26739", "n": 3}
+                        ]
+                    ]
+                }
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<HyperliquidOrderBookL2>(input).unwrap(),
+                HyperliquidOrderBookL2 {
+                    channel: "l2Book".to_string(),
+                    data: HyperliquidOrderBookL2Inner {
+                        coin: "BTC".to_string(),
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1751427259657
+                        )),
+                        levels: [
+                            vec![
+                                HyperliquidLevel {
+                                    price: dec!(106217.0),
+                                    amount: dec!(0.001),
+                                    orders: 1,
+                                },
+                                HyperliquidLevel {
+                                    price: dec!(106216.0),
+                                    amount: dec!(0.5),
+                                    orders: 2,
+                                },
+                            ],
+                            vec![HyperliquidLevel {
+                                price: dec!(106233.0),
+                                amount: dec!(0.26739),
+                                orders: 3,
+                            }],
+                        ],
+                    },
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_hyperliquid_order_book_l2_to_market_iter() {
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1751427259657));
+        let level = |price, amount| HyperliquidLevel {
+            price,
+            amount,
+            orders: 1,
+        };
+
+        let input = HyperliquidOrderBookL2 {
+            channel: "l2Book".to_string(),
+            data: HyperliquidOrderBookL2Inner {
+                coin: "BTC".to_string(),
+                time,
+                levels: [
+                    vec![
+                        level(dec!(106216.0), dec!(0.5)),
+                        level(dec!(106217.0), dec!(0.001)),
+                    ],
+                    vec![level(dec!(106233.0), dec!(0.26739))],
+                ],
+            },
+        };
+
+        assert_eq!(input.id(), Some(SubscriptionId::from("l2Book|BTC")));
+
+        let actual = MarketIter::<&str, OrderBookEvent>::from((
+            ExchangeId::Hyperliquid,
+            "btc_usdc_perp",
+            input,
+        ))
+        .0
+        .into_iter()
+        .map(|event| event.unwrap().kind)
+        .collect::<Vec<_>>();
+
+        // Every message is a full snapshot, with bids sorted best first
+        assert_eq!(
+            actual,
+            vec![OrderBookEvent::Snapshot(OrderBook::new(
+                1751427259657,
+                Some(time),
+                vec![
+                    Level::new(dec!(106217.0), dec!(0.001)),
+                    Level::new(dec!(106216.0), dec!(0.5)),
+                ],
+                vec![Level::new(dec!(106233.0), dec!(0.26739))],
+            ))]
+        );
+    }
+}
@@ -0,0 +1,35 @@
+use crate::books::Level;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Level 1 OrderBook types (top of book).
+pub mod l1;
+
+/// Level 2 OrderBook types.
+pub mod l2;
+
+/// [`Hyperliquid`](super::Hyperliquid) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+/// ```json
+/// {"px": "106217.0", "sz": "0.001", "n": 1}
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct HyperliquidLevel {
+    #[serde(rename = "px")]
+    pub price: Decimal,
+    #[serde(rename = "sz")]
+    pub amount: Decimal,
+    #[serde(rename = "n")]
+    pub orders: u64,
+}
+
+impl From<HyperliquidLevel> for Level {
+    fn from(level: HyperliquidLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
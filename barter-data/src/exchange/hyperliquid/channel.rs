@@ -0,0 +1,60 @@
+use super::Hyperliquid;
+use crate::{
+    Identifier,
+    subscription::{
+        Subscription,
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+    },
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Hyperliquid`] channel (subscription type) to be subscribed to.
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct HyperliquidChannel(pub &'static str);
+
+impl HyperliquidChannel {
+    /// [`Hyperliquid`] real-time trades channel.
+    pub const TRADES: Self = Self("trades");
+
+    /// [`Hyperliquid`] real-time OrderBook Level1 (best bid and offer) channel.
+    pub const ORDER_BOOK_L1: Self = Self("bbo");
+
+    /// [`Hyperliquid`] real-time OrderBook Level2 channel.
+    ///
+    /// Each message is a full snapshot of up to 20 levels per side.
+    pub const ORDER_BOOK_L2: Self = Self("l2Book");
+}
+
+impl<Instrument> Identifier<HyperliquidChannel>
+    for Subscription<Hyperliquid, Instrument, PublicTrades>
+{
+    fn id(&self) -> HyperliquidChannel {
+        HyperliquidChannel::TRADES
+    }
+}
+
+impl<Instrument> Identifier<HyperliquidChannel>
+    for Subscription<Hyperliquid, Instrument, OrderBooksL1>
+{
+    fn id(&self) -> HyperliquidChannel {
+        HyperliquidChannel::ORDER_BOOK_L1
+    }
+}
+
+impl<Instrument> Identifier<HyperliquidChannel>
+    for Subscription<Hyperliquid, Instrument, OrderBooksL2>
+{
+    fn id(&self) -> HyperliquidChannel {
+        HyperliquidChannel::ORDER_BOOK_L2
+    }
+}
+
+impl AsRef<str> for HyperliquidChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
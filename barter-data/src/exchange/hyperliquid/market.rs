@@ -0,0 +1,97 @@
+use super::Hyperliquid;
+use crate::{Identifier, instrument::MarketInstrumentData, subscription::Subscription};
+use barter_instrument::{
+    Keyed, asset::name::AssetNameInternal, instrument::market_data::MarketDataInstrument,
+};
+use serde::{Deserialize, Serialize};
+use smol_str::{SmolStr, StrExt, format_smolstr};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Hyperliquid`] market (coin) that can be subscribed to.
+///
+/// Hyperliquid perpetuals are identified by their base coin (eg/ "BTC"), since they all
+/// settle in USDC. Subscriptions with a quote other than USD or USDC are mapped to a
+/// "{BASE}-{QUOTE}" coin that Hyperliquid does not list, so the exchange rejects them rather than
+/// silently streaming the USDC perpetual.
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct HyperliquidMarket(pub SmolStr);
+
+impl<Kind> Identifier<HyperliquidMarket> for Subscription<Hyperliquid, MarketDataInstrument, Kind> {
+    fn id(&self) -> HyperliquidMarket {
+        hyperliquid_market(&self.instrument.base, &self.instrument.quote)
+    }
+}
+
+impl<InstrumentKey, Kind> Identifier<HyperliquidMarket>
+    for Subscription<Hyperliquid, Keyed<InstrumentKey, MarketDataInstrument>, Kind>
+{
+    fn id(&self) -> HyperliquidMarket {
+        hyperliquid_market(&self.instrument.value.base, &self.instrument.value.quote)
+    }
+}
+
+impl<InstrumentKey, Kind> Identifier<HyperliquidMarket>
+    for Subscription<Hyperliquid, MarketInstrumentData<InstrumentKey>, Kind>
+{
+    fn id(&self) -> HyperliquidMarket {
+        HyperliquidMarket(self.instrument.name_exchange.name().clone())
+    }
+}
+
+impl AsRef<str> for HyperliquidMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn hyperliquid_market(base: &AssetNameInternal, quote: &AssetNameInternal) -> HyperliquidMarket {
+    let base = base.name().to_uppercase_smolstr();
+
+    if ["usd", "usdc"].contains(&quote.name().to_lowercase().as_str()) {
+        HyperliquidMarket(base)
+    } else {
+        HyperliquidMarket(format_smolstr!(
+            "{base}-{}",
+            quote.name().to_uppercase_smolstr()
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyperliquid_market() {
+        struct TestCase {
+            input: (&'static str, &'static str),
+            expected: HyperliquidMarket,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: USDC quote maps to base coin
+                input: ("btc", "usdc"),
+                expected: HyperliquidMarket("BTC".into()),
+            },
+            TestCase {
+                // TC1: USD quote maps to base coin
+                input: ("eth", "usd"),
+                expected: HyperliquidMarket("ETH".into()),
+            },
+            TestCase {
+                // TC2: unsupported quote maps to an unlisted coin
+                input: ("btc", "usdt"),
+                expected: HyperliquidMarket("BTC-USDT".into()),
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let (base, quote) = test.input;
+            let actual = hyperliquid_market(&base.into(), &quote.into());
+            assert_eq!(actual, test.expected, "TC{index} failed");
+        }
+    }
+}
@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// [`Hyperliquid`](super::Hyperliquid) market data WebSocket message.
+///
+/// The "data" field contents depend on the channel, see the channel specific types for payload
+/// examples:
+/// - [`HyperliquidTrades`](super::trade::HyperliquidTrades)
+/// - [`HyperliquidOrderBookL1`](super::book::l1::HyperliquidOrderBookL1)
+/// - [`HyperliquidOrderBookL2`](super::book::l2::HyperliquidOrderBookL2)
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct HyperliquidMessage<T> {
+    pub channel: String,
+    pub data: T,
+}
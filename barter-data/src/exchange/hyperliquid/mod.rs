@@ -0,0 +1,204 @@
+use self::{
+    book::{l1::HyperliquidOrderBookL1, l2::HyperliquidOrderBookL2},
+    channel::HyperliquidChannel,
+    market::HyperliquidMarket,
+    subscription::{HyperliquidSubResponse, HyperliquidSubscription},
+    trade::HyperliquidTrades,
+};
+use crate::{
+    ExchangeWsStream, NoInitialSnapshots,
+    exchange::{Connector, ExchangeSub, PingInterval, StreamSelector, SubscribeRateLimit},
+    instrument::InstrumentData,
+    subscriber::{WebSocketSubscriber, validator::WebSocketSubValidator},
+    subscription::{
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+    },
+    transformer::stateless::StatelessTransformer,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{
+    error::SocketError,
+    protocol::websocket::{WebSocketSerdeParser, WsMessage},
+};
+use barter_macro::{DeExchange, SerExchange};
+use derive_more::Display;
+use serde_json::json;
+use std::{num::NonZeroUsize, time::Duration};
+use url::Url;
+
+/// OrderBook types for [`Hyperliquid`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// Generic [`HyperliquidMessage<T>`](message::HyperliquidMessage) type common to all
+/// [`Hyperliquid`] market data channels.
+pub mod message;
+
+/// [`Subscription`](crate::subscription::Subscription) request & response types, and response
+/// [`Validator`](barter_integration::Validator) for [`Hyperliquid`].
+pub mod subscription;
+
+/// Public trade types for [`Hyperliquid`].
+pub mod trade;
+
+/// [`Hyperliquid`] server base url.
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket>
+pub const BASE_URL_HYPERLIQUID: &str = "wss://api.hyperliquid.xyz/ws";
+
+/// [`Hyperliquid`] server [`PingInterval`] duration, within the 60 second window after which
+/// idle connections are closed.
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/timeouts-and-heartbeats>
+pub const PING_INTERVAL_HYPERLIQUID: Duration = Duration::from_secs(50);
+
+/// [`Hyperliquid`] [`SubscribeRateLimit`] of WebSocket messages sent per connection.
+///
+/// Hyperliquid applies this limit across all connections from an IP address, but it is only
+/// enforced per connection here. Callers opening several connections must keep their combined
+/// subscriptions within the limit.
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/rate-limits-and-user-limits>
+pub const SUBSCRIBE_RATE_LIMIT_HYPERLIQUID: SubscribeRateLimit = SubscribeRateLimit {
+    max_messages: NonZeroUsize::new(2000).unwrap(),
+    interval: Duration::from_secs(60),
+};
+
+/// Convenient type alias for a Hyperliquid [`ExchangeWsStream`] using [`WebSocketSerdeParser`](barter_integration::protocol::websocket::WebSocketSerdeParser).
+pub type HyperliquidWsStream<Transformer> = ExchangeWsStream<WebSocketSerdeParser, Transformer>;
+
+/// [`Hyperliquid`] perpetuals exchange.
+///
+/// ### Notes
+/// Hyperliquid does not publish a public liquidations feed over WebSocket (liquidation fills are
+/// only available via user scoped channels), so `Liquidations` are not supported.
+///
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket>
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    Default,
+    Display,
+    DeExchange,
+    SerExchange,
+)]
+pub struct Hyperliquid;
+
+impl Connector for Hyperliquid {
+    const ID: ExchangeId = ExchangeId::Hyperliquid;
+    type Channel = HyperliquidChannel;
+    type Market = HyperliquidMarket;
+    type Subscriber = WebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = HyperliquidSubResponse;
+
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_HYPERLIQUID).map_err(SocketError::UrlParse)
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            interval: tokio::time::interval(PING_INTERVAL_HYPERLIQUID),
+            ping: || WsMessage::text(json!({ "method": "ping" }).to_string()),
+        })
+    }
+
+    fn subscribe_rate_limit() -> Option<SubscribeRateLimit> {
+        Some(SUBSCRIBE_RATE_LIMIT_HYPERLIQUID)
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        // Hyperliquid accepts a single subscription per subscribe request
+        exchange_subs
+            .iter()
+            .map(|sub| {
+                WsMessage::text(
+                    json!({
+                        "method": "subscribe",
+                        "subscription": HyperliquidSubscription::from(sub),
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<Instrument> StreamSelector<Instrument, PublicTrades> for Hyperliquid
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = HyperliquidWsStream<
+        StatelessTransformer<Self, Instrument::Key, PublicTrades, HyperliquidTrades>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for Hyperliquid
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = HyperliquidWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL1, HyperliquidOrderBookL1>,
+    >;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL2> for Hyperliquid
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = HyperliquidWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL2, HyperliquidOrderBookL2>,
+    >;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_hyperliquid_requests() {
+        let actual = Hyperliquid::requests(vec![
+            ExchangeSub::from((HyperliquidChannel::TRADES, HyperliquidMarket("BTC".into()))),
+            ExchangeSub::from((
+                HyperliquidChannel::ORDER_BOOK_L2,
+                HyperliquidMarket("ETH".into()),
+            )),
+        ])
+        .into_iter()
+        .map(|message| {
+            serde_json::from_str::<serde_json::Value>(message.to_text().unwrap()).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                json!({
+                    "method": "subscribe",
+                    "subscription": {"type": "trades", "coin": "BTC"},
+                }),
+                json!({
+                    "method": "subscribe",
+                    "subscription": {"type": "l2Book", "coin": "ETH"},
+                }),
+            ]
+        );
+    }
+}
@@ -0,0 +1,183 @@
+use super::{channel::HyperliquidChannel, market::HyperliquidMarket};
+use crate::exchange::subscription::ExchangeSub;
+use barter_integration::{Validator, error::SocketError};
+use serde::{Deserialize, Serialize};
+
+/// [`Hyperliquid`](super::Hyperliquid) WebSocket subscription, sent as the "subscription" field
+/// of a subscribe request.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+/// ```json
+/// {"type": "trades", "coin": "BTC"}
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct HyperliquidSubscription {
+    #[serde(rename = "type")]
+    pub channel: String,
+    pub coin: String,
+}
+
+impl From<&ExchangeSub<HyperliquidChannel, HyperliquidMarket>> for HyperliquidSubscription {
+    fn from(sub: &ExchangeSub<HyperliquidChannel, HyperliquidMarket>) -> Self {
+        Self {
+            channel: sub.channel.as_ref().to_string(),
+            coin: sub.market.as_ref().to_string(),
+        }
+    }
+}
+
+/// [`Hyperliquid`](super::Hyperliquid) WebSocket subscription response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket#subscriptions>
+/// #### Subscription Success
+/// ```json
+/// {
+///     "channel": "subscriptionResponse",
+///     "data": {
+///         "method": "subscribe",
+///         "subscription": {"type": "trades", "coin": "BTC"}
+///     }
+/// }
+/// ```
+///
+/// #### Subscription Failure
+/// ```json
+/// {
+///     "channel": "error",
+///     "data": "Invalid subscription {\"type\":\"trades\",\"coin\":\"GIBBERISH\"}"
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "channel", content = "data", rename_all = "camelCase")]
+pub enum HyperliquidSubResponse {
+    SubscriptionResponse {
+        method: String,
+        subscription: HyperliquidSubscription,
+    },
+    Error(String),
+}
+
+impl Validator for HyperliquidSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            HyperliquidSubResponse::SubscriptionResponse { .. } => Ok(self),
+            HyperliquidSubResponse::Error(message) => Err(SocketError::Subscribe(format!(
+                "received failure subscription response: {message}",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_hyperliquid_sub_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<HyperliquidSubResponse, SocketError>,
+            }
+
+            let cases = vec![
+                TestCase {
+                    // TC0: input response is SubscriptionResponse
+                    input: r#"
+                    {
+                        "channel": "subscriptionResponse",
+                        "data": {
+                            "method": "subscribe",
+                            "subscription": {"type": "trades", "coin": "BTC"}
+                        }
+                    }
+                    "#,
+                    expected: Ok(HyperliquidSubResponse::SubscriptionResponse {
+                        method: "subscribe".to_string(),
+                        subscription: HyperliquidSubscription {
+                            channel: "trades".to_string(),
+                            coin: "BTC".to_string(),
+                        },
+                    }),
+                },
+                TestCase {
+                    // TC1: input response is failed subscription
+                    input: r#"
+                    {
+                        "channel": "error",
+                        "data": "Invalid subscription {\"type\":\"trades\",\"coin\":\"GIBBERISH\"}"
+                    }
+                    "#,
+                    expected: Ok(HyperliquidSubResponse::Error(
+                        r#"Invalid subscription {"type":"trades","coin":"GIBBERISH"}"#.to_string(),
+                    )),
+                },
+                TestCase {
+                    // TC2: input is a market data message, not a subscription response
+                    input: r#"{"channel": "trades", "data": []}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "".to_string(),
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = serde_json::from_str::<HyperliquidSubResponse>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!(
+                            "TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_hyperliquid_sub_response() {
+        struct TestCase {
+            input_response: HyperliquidSubResponse,
+            is_valid: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input_response: HyperliquidSubResponse::SubscriptionResponse {
+                    method: "subscribe".to_string(),
+                    subscription: HyperliquidSubscription {
+                        channel: "trades".to_string(),
+                        coin: "BTC".to_string(),
+                    },
+                },
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input_response: HyperliquidSubResponse::Error("Invalid subscription".to_string()),
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input_response.validate().is_ok();
+            assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use super::{channel::HyperliquidChannel, message::HyperliquidMessage};
+use crate::{
+    Identifier,
+    event::{MarketEvent, MarketIter},
+    exchange::ExchangeSub,
+    subscription::trade::PublicTrade,
+};
+use barter_instrument::{Side, exchange::ExchangeId};
+use barter_integration::subscription::SubscriptionId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Hyperliquid`](super::Hyperliquid) real-time trades WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://hyperliquid.gitbook.io/hyperliquid-docs/for-developers/api/websocket/subscriptions>
+/// ```json
+/// {
+///     "channel": "trades",
+///     "data": [
+///         {
+///             "coin": "BTC",
+///             "side": "A",
+///             "px": "106296.0",
+///             "sz": "0.00017",
+///             "time": 1751430933565,
+///             "hash": "0xde93a8d8a1ea6ef9a6e80425a0e2b60001a0018b5c1e9c0d30b76e2ff4d5ab2c",
+///             "tid": 293353986402527,
+///             "users": [
+///                 "0xcc0a3b6e3dd4cd0a9b2b9b4d8d3cbd23a0c9a6ee",
+///                 "0x0d1d9635d0640821d15e323ac8adadfa9c111414"
+///             ]
+///         }
+///     ]
+/// }
+/// ```
+pub type HyperliquidTrades = HyperliquidMessage<Vec<HyperliquidTrade>>;
+
+/// [`Hyperliquid`](super::Hyperliquid) real-time trade.
+///
+/// See [`HyperliquidTrades`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct HyperliquidTrade {
+    pub coin: String,
+    #[serde(rename = "tid")]
+    pub id: u64,
+    #[serde(rename = "px", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    #[serde(deserialize_with = "de_hyperliquid_side")]
+    pub side: Side,
+    #[serde(deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+impl Identifier<Option<SubscriptionId>> for HyperliquidTrades {
+    fn id(&self) -> Option<SubscriptionId> {
+        // All trades in a message are associated with the same coin
+        self.data
+            .first()
+            .map(|trade| ExchangeSub::from((HyperliquidChannel::TRADES, &trade.coin)).id())
+    }
+}
+
+impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, HyperliquidTrades)>
+    for MarketIter<InstrumentKey, PublicTrade>
+{
+    fn from(
+        (exchange, instrument, trades): (ExchangeId, InstrumentKey, HyperliquidTrades),
+    ) -> Self {
+        trades
+            .data
+            .into_iter()
+            .map(|trade| {
+                Ok(MarketEvent {
+                    time_exchange: trade.time,
+                    time_received: Utc::now(),
+                    exchange,
+                    instrument: instrument.clone(),
+                    kind: PublicTrade {
+                        id: trade.id.to_string(),
+                        price: trade.price,
+                        amount: trade.amount,
+                        side: trade.side,
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Deserialize a [`Hyperliquid`](super::Hyperliquid) aggressor side, where "B" is a buy (bid)
+/// and "A" is a sell (ask), as a Barter [`Side`].
+pub fn de_hyperliquid_side<'de, D>(deserializer: D) -> Result<Side, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    match <&str as Deserialize>::deserialize(deserializer)? {
+        "B" => Ok(Side::Buy),
+        "A" => Ok(Side::Sell),
+        other => Err(serde::de::Error::invalid_value(
+            serde::de::Unexpected::Str(other),
+            &"B | A",
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::de::datetime_utc_from_epoch_duration;
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_hyperliquid_trades() {
+            struct TestCase {
+                input: &'static str,
+                expected: Option<HyperliquidTrades>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid HyperliquidTrades w/ sell & buy trades
+                    input: r#"
+                    {
+                        "channel": "trades",
+                        "data": [
+                            {
+                                "coin": "BTC", "side": "A", "px": "106296.0", "sz": "0.00017",
+                                "time": 1751430933565, "hash": "0xde93", "tid": 293353986402527,
+                                "users": ["0xcc0a", "0x0d1d"]
+                            },
+                            {
+                                "coin": "BTC", "side": "B", "px": "106297.0", "sz": "0.5",
+                                "time": 1751430933566, "hash": "0xde94", "tid": 293353986402528,
+                                "users": ["0xcc0b", "0x0d1e"]
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: Some(HyperliquidTrades {
+                        channel: "trades".to_string(),
+                        data: vec![
+                            HyperliquidTrade {
+                                coin: "BTC".to_string(),
+                                id: 293353986402527,
+                                price: 106296.0,
+                                amount: 0.00017,
+                                side: Side::Sell,
+                                time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                    1751430933565,
+                                )),
+                            },
+                            HyperliquidTrade {
+                                coin: "BTC".to_string(),
+                                id: 293353986402528,
+                                price: 106297.0,
+                                amount: 0.5,
+                                side: Side::Buy,
+                                time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                    1751430933566,
+                                )),
+                            },
+                        ],
+                    }),
+                },
+                TestCase {
+                    // TC1: invalid HyperliquidTrades w/ unknown side
+                    input: r#"
+                    {
+                        "channel": "trades",
+                        "data": [
+                            {
+                                "coin": "BTC", "side": "X", "px": "106296.0", "sz": "0.00017",
+                                "time": 1751430933565, "hash": "0xde93", "tid": 293353986402527,
+                                "users": ["0xcc0a", "0x0d1d"]
+                            }
+                        ]
+                    }
+                    "#,
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<HyperliquidTrades>(test.input).ok();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hyperliquid_trades_id() {
+        let trades = HyperliquidTrades {
+            channel: "trades".to_string(),
+            data: vec![HyperliquidTrade {
+                coin: "ETH".to_string(),
+                id: 1,
+                price: 2500.0,
+                amount: 1.0,
+                side: Side::Buy,
+                time: datetime_utc_from_epoch_duration(Duration::from_millis(1)),
+            }],
+        };
+
+        assert_eq!(trades.id(), Some(SubscriptionId::from("trades|ETH")));
+    }
+}
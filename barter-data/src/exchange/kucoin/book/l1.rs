@@ -0,0 +1,140 @@
+use super::super::message::KucoinMessage;
+use crate::{
+    books::Level,
+    event::{MarketEvent, MarketIter},
+    subscription::book::OrderBookL1,
+};
+use barter_instrument::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for a [`Kucoin`](super::super::Kucoin) real-time OrderBook Level1
+/// (top of book) WebSocket message.
+pub type KucoinOrderBookL1 = KucoinMessage<KucoinOrderBookL1Inner>;
+
+/// [`Kucoin`](super::super::Kucoin) real-time best bid and ask.
+///
+/// Levels are sent as a `[price, size]` pair, and may be omitted if that side of the book is
+/// empty.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level1-bbo-market-data>
+/// ```json
+/// {
+///     "type": "message",
+///     "topic": "/spotMarket/level1:BTC-USDT",
+///     "subject": "level1",
+///     "data": {
+///         "asks": ["9989", "8"],
+///         "bids": ["9984", "10"],
+///         "timestamp": 1586948108193
+///     }
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinOrderBookL1Inner {
+    #[serde(default)]
+    pub bids: Option<Level>,
+    #[serde(default)]
+    pub asks: Option<Level>,
+    #[serde(
+        rename = "timestamp",
+        deserialize_with = "barter_integration::de::de_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, KucoinOrderBookL1)>
+    for MarketIter<InstrumentKey, OrderBookL1>
+{
+    fn from((exchange, instrument, book): (ExchangeId, InstrumentKey, KucoinOrderBookL1)) -> Self {
+        let book = book.data;
+
+        Self(vec![Ok(MarketEvent {
+            time_exchange: book.time,
+            time_received: Utc::now(),
+            exchange,
+            instrument,
+            kind: OrderBookL1 {
+                last_update_time: book.time,
+                best_bid: book.bids,
+                best_ask: book.asks,
+            },
+        })])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{de::datetime_utc_from_epoch_duration, subscription::SubscriptionId};
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kucoin_message_order_book_l1() {
+            struct TestCase {
+                input: &'static str,
+                expected: KucoinOrderBookL1,
+            }
+
+            let time = datetime_utc_from_epoch_duration(Duration::from_millis(1586948108193));
+
+            let tests = vec![
+                TestCase {
+                    // TC0: valid KucoinOrderBookL1 with both sides
+                    input: r#"
+                    {
+                        "type": "message",
+                        "topic": "/spotMarket/level1:BTC-USDT",
+                        "subject": "level1",
+                        "data": {
+                            "asks": ["9989", "8"],
+                            "bids": ["9984", "10"],
+                            "timestamp": 1586948108193
+                        }
+                    }
+                    "#,
+                    expected: KucoinOrderBookL1 {
+                        subscription_id: SubscriptionId::from("/spotMarket/level1|BTC-USDT"),
+                        data: KucoinOrderBookL1Inner {
+                            bids: Some(Level::new(dec!(9984), dec!(10))),
+                            asks: Some(Level::new(dec!(9989), dec!(8))),
+                            time,
+                        },
+                    },
+                },
+                TestCase {
+                    // TC1: valid KucoinOrderBookL1 with empty asks omitted
+                    input: r#"
+                    {
+                        "type": "message",
+                        "topic": "/spotMarket/level1:BTC-USDT",
+                        "subject": "level1",
+                        "data": {
+                            "bids": ["9984", "10"],
+                            "timestamp": 1586948108193
+                        }
+                    }
+                    "#,
+                    expected: KucoinOrderBookL1 {
+                        subscription_id: SubscriptionId::from("/spotMarket/level1|BTC-USDT"),
+                        data: KucoinOrderBookL1Inner {
+                            bids: Some(Level::new(dec!(9984), dec!(10))),
+                            asks: None,
+                            time,
+                        },
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KucoinOrderBookL1>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
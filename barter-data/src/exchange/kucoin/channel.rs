@@ -0,0 +1,43 @@
+use super::Kucoin;
+use crate::{
+    Identifier,
+    subscription::{Subscription, book::OrderBooksL1, trade::PublicTrades},
+};
+use serde::Serialize;
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Kucoin`] channel (topic prefix) to be subscribed to.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/subscribe/introduction>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+pub struct KucoinChannel(pub &'static str);
+
+impl KucoinChannel {
+    /// [`Kucoin`] real-time trades channel.
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/match-execution-data>
+    pub const TRADES: Self = Self("/market/match");
+
+    /// [`Kucoin`] real-time OrderBook Level1 (top of book) channel.
+    ///
+    /// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/level1-bbo-market-data>
+    pub const ORDER_BOOK_L1: Self = Self("/spotMarket/level1");
+}
+
+impl<Instrument> Identifier<KucoinChannel> for Subscription<Kucoin, Instrument, PublicTrades> {
+    fn id(&self) -> KucoinChannel {
+        KucoinChannel::TRADES
+    }
+}
+
+impl<Instrument> Identifier<KucoinChannel> for Subscription<Kucoin, Instrument, OrderBooksL1> {
+    fn id(&self) -> KucoinChannel {
+        KucoinChannel::ORDER_BOOK_L1
+    }
+}
+
+impl AsRef<str> for KucoinChannel {
+    fn as_ref(&self) -> &str {
+        self.0
+    }
+}
@@ -0,0 +1,46 @@
+use super::Kucoin;
+use crate::{Identifier, instrument::MarketInstrumentData, subscription::Subscription};
+use barter_instrument::{
+    Keyed, asset::name::AssetNameInternal, instrument::market_data::MarketDataInstrument,
+};
+use serde::{Deserialize, Serialize};
+use smol_str::{SmolStr, StrExt, format_smolstr};
+
+/// Type that defines how to translate a Barter [`Subscription`] into a
+/// [`Kucoin`] market that can be subscribed to.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/subscribe/introduction>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinMarket(pub SmolStr);
+
+impl<Kind> Identifier<KucoinMarket> for Subscription<Kucoin, MarketDataInstrument, Kind> {
+    fn id(&self) -> KucoinMarket {
+        kucoin_market(&self.instrument.base, &self.instrument.quote)
+    }
+}
+
+impl<InstrumentKey, Kind> Identifier<KucoinMarket>
+    for Subscription<Kucoin, Keyed<InstrumentKey, MarketDataInstrument>, Kind>
+{
+    fn id(&self) -> KucoinMarket {
+        kucoin_market(&self.instrument.value.base, &self.instrument.value.quote)
+    }
+}
+
+impl<InstrumentKey, Kind> Identifier<KucoinMarket>
+    for Subscription<Kucoin, MarketInstrumentData<InstrumentKey>, Kind>
+{
+    fn id(&self) -> KucoinMarket {
+        KucoinMarket(self.instrument.name_exchange.name().clone())
+    }
+}
+
+impl AsRef<str> for KucoinMarket {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+fn kucoin_market(base: &AssetNameInternal, quote: &AssetNameInternal) -> KucoinMarket {
+    KucoinMarket(format_smolstr!("{base}-{quote}").to_uppercase_smolstr())
+}
@@ -0,0 +1,107 @@
+use crate::{Identifier, exchange::ExchangeSub};
+use barter_integration::subscription::SubscriptionId;
+use serde::{Deserialize, Serialize};
+
+/// [`Kucoin`](super::Kucoin) market data WebSocket message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/match-execution-data>
+/// #### Trade
+/// ```json
+/// {
+///     "type": "message",
+///     "topic": "/market/match:BTC-USDT",
+///     "subject": "trade.l3match",
+///     "data": {
+///         "makerOrderId": "6287c3015c27f000017d0c2f",
+///         "price": "31078.80000000000000000000",
+///         "sequence": "11623989",
+///         "side": "buy",
+///         "size": "0.00015200000000000000",
+///         "symbol": "BTC-USDT",
+///         "takerOrderId": "6287c3015c27f000017d0c32",
+///         "time": "1652999937016234900",
+///         "tradeId": "6287c3015c27f000017d0c33",
+///         "type": "match"
+///     }
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinMessage<T> {
+    #[serde(
+        rename = "topic",
+        deserialize_with = "de_kucoin_message_topic_as_subscription_id"
+    )]
+    pub subscription_id: SubscriptionId,
+    pub data: T,
+}
+
+impl<T> Identifier<Option<SubscriptionId>> for KucoinMessage<T> {
+    fn id(&self) -> Option<SubscriptionId> {
+        Some(self.subscription_id.clone())
+    }
+}
+
+/// Deserialize a [`KucoinMessage`] "topic" (eg/ "/market/match:BTC-USDT") as the associated
+/// [`SubscriptionId`] (eg/ "/market/match|BTC-USDT").
+pub fn de_kucoin_message_topic_as_subscription_id<'de, D>(
+    deserializer: D,
+) -> Result<SubscriptionId, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    let topic = <&str as Deserialize>::deserialize(deserializer)?;
+
+    topic
+        .split_once(':')
+        .map(|(channel, market)| ExchangeSub::from((channel, market)).id())
+        .ok_or_else(|| {
+            serde::de::Error::invalid_value(
+                serde::de::Unexpected::Str(topic),
+                &"Kucoin topic of the form: channel:market",
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use serde_json::Value;
+
+        #[test]
+        fn test_kucoin_message_subscription_id() {
+            struct TestCase {
+                input: &'static str,
+                expected: Option<SubscriptionId>,
+            }
+
+            let cases = vec![
+                TestCase {
+                    // TC0: valid trades topic
+                    input: r#"{"type": "message", "topic": "/market/match:BTC-USDT", "data": {}}"#,
+                    expected: Some(SubscriptionId::from("/market/match|BTC-USDT")),
+                },
+                TestCase {
+                    // TC1: valid level1 topic
+                    input: r#"{"type": "message", "topic": "/spotMarket/level1:ETH-USDT", "data": {}}"#,
+                    expected: Some(SubscriptionId::from("/spotMarket/level1|ETH-USDT")),
+                },
+                TestCase {
+                    // TC2: invalid topic without market
+                    input: r#"{"type": "message", "topic": "/market/match", "data": {}}"#,
+                    expected: None,
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KucoinMessage<Value>>(test.input)
+                    .ok()
+                    .map(|message| message.subscription_id);
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+}
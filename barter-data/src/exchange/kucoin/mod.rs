@@ -0,0 +1,213 @@
+use self::{
+    book::l1::KucoinOrderBookL1, channel::KucoinChannel, market::KucoinMarket,
+    subscriber::KucoinWebSocketSubscriber, subscription::KucoinSubResponse, trade::KucoinTrade,
+};
+use crate::{
+    ExchangeWsStream, NoInitialSnapshots,
+    exchange::{Connector, ExchangeSub, PingInterval, StreamSelector, SubscribeRateLimit},
+    instrument::InstrumentData,
+    subscriber::validator::WebSocketSubValidator,
+    subscription::{book::OrderBooksL1, trade::PublicTrades},
+    transformer::stateless::StatelessTransformer,
+};
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{
+    error::SocketError,
+    protocol::websocket::{WebSocketSerdeParser, WsMessage},
+};
+use barter_macro::{DeExchange, SerExchange};
+use derive_more::Display;
+use serde_json::json;
+use std::{num::NonZeroUsize, time::Duration};
+use url::Url;
+
+/// OrderBook types for [`Kucoin`].
+pub mod book;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
+pub mod channel;
+
+/// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
+/// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
+pub mod market;
+
+/// Generic [`KucoinMessage<T>`](message::KucoinMessage) type common to all [`Kucoin`] market
+/// data channels.
+pub mod message;
+
+/// Custom [`Subscriber`](crate::subscriber::Subscriber) for [`Kucoin`] that requests a
+/// connection token before connecting.
+pub mod subscriber;
+
+/// [`Subscription`](crate::subscription::Subscription) response type and response
+/// [`Validator`](barter_integration::Validator) for [`Kucoin`].
+pub mod subscription;
+
+/// Public trade types for [`Kucoin`].
+pub mod trade;
+
+/// [`Kucoin`] spot WebSocket base url.
+///
+/// Connections require a token, so the [`KucoinWebSocketSubscriber`] connects to the endpoint
+/// returned alongside the token from [`HTTP_BULLET_PUBLIC_URL_KUCOIN`] rather than this url.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/create-connection>
+pub const BASE_URL_KUCOIN: &str = "wss://ws-api-spot.kucoin.com/";
+
+/// [`Kucoin`] public connection token url, used to request the dynamic WebSocket endpoint.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+pub const HTTP_BULLET_PUBLIC_URL_KUCOIN: &str = "https://api.kucoin.com/api/v1/bullet-public";
+
+/// Default [`Kucoin`] server [`PingInterval`] duration.
+///
+/// Each connection uses the server `pingInterval` provided by its connection token response
+/// instead, falling back to this duration if none is provided.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/ping>
+pub const PING_INTERVAL_KUCOIN: Duration = Duration::from_secs(18);
+
+/// [`Kucoin`] [`SubscribeRateLimit`] of client messages per connection, leaving headroom below
+/// the 100 messages per 10 seconds limit for application-level pings.
+///
+/// See docs: <https://www.kucoin.com/docs/basic-info/request-rate-limit/websocket>
+pub const SUBSCRIBE_RATE_LIMIT_KUCOIN: SubscribeRateLimit = SubscribeRateLimit {
+    max_messages: NonZeroUsize::new(90).unwrap(),
+    interval: Duration::from_secs(10),
+};
+
+/// Convenient type alias for a Kucoin [`ExchangeWsStream`] using [`WebSocketSerdeParser`](barter_integration::protocol::websocket::WebSocketSerdeParser).
+pub type KucoinWsStream<Transformer> = ExchangeWsStream<WebSocketSerdeParser, Transformer>;
+
+/// [`Kucoin`] spot exchange.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/introduction>
+#[derive(
+    Copy,
+    Clone,
+    Eq,
+    PartialEq,
+    Ord,
+    PartialOrd,
+    Hash,
+    Debug,
+    Default,
+    Display,
+    DeExchange,
+    SerExchange,
+)]
+pub struct Kucoin;
+
+impl Connector for Kucoin {
+    const ID: ExchangeId = ExchangeId::Kucoin;
+    type Channel = KucoinChannel;
+    type Market = KucoinMarket;
+    type Subscriber = KucoinWebSocketSubscriber;
+    type SubValidator = WebSocketSubValidator;
+    type SubResponse = KucoinSubResponse;
+
+    /// [`Kucoin`] WebSocket endpoints are dynamic, so the [`KucoinWebSocketSubscriber`] connects
+    /// to the endpoint provided by the connection token response rather than this url.
+    fn url() -> Result<Url, SocketError> {
+        Url::parse(BASE_URL_KUCOIN).map_err(SocketError::UrlParse)
+    }
+
+    fn ping_interval() -> Option<PingInterval> {
+        Some(PingInterval {
+            interval: tokio::time::interval(PING_INTERVAL_KUCOIN),
+            ping: || {
+                WsMessage::text(
+                    json!({
+                        "id": chrono::Utc::now().timestamp_millis().to_string(),
+                        "type": "ping",
+                    })
+                    .to_string(),
+                )
+            },
+        })
+    }
+
+    fn subscribe_rate_limit() -> Option<SubscribeRateLimit> {
+        Some(SUBSCRIBE_RATE_LIMIT_KUCOIN)
+    }
+
+    fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
+        exchange_subs
+            .into_iter()
+            .enumerate()
+            .map(|(index, ExchangeSub { channel, market })| {
+                WsMessage::text(
+                    json!({
+                        "id": (index + 1).to_string(),
+                        "type": "subscribe",
+                        "topic": format!("{}:{}", channel.as_ref(), market.as_ref()),
+                        "privateChannel": false,
+                        "response": true,
+                    })
+                    .to_string(),
+                )
+            })
+            .collect()
+    }
+}
+
+impl<Instrument> StreamSelector<Instrument, PublicTrades> for Kucoin
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        KucoinWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, KucoinTrade>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for Kucoin
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = KucoinWsStream<
+        StatelessTransformer<Self, Instrument::Key, OrderBooksL1, KucoinOrderBookL1>,
+    >;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kucoin_requests() {
+        let actual = Kucoin::requests(vec![
+            ExchangeSub::from((KucoinChannel::TRADES, KucoinMarket("BTC-USDT".into()))),
+            ExchangeSub::from((
+                KucoinChannel::ORDER_BOOK_L1,
+                KucoinMarket("ETH-USDT".into()),
+            )),
+        ])
+        .into_iter()
+        .map(|message| {
+            serde_json::from_str::<serde_json::Value>(message.to_text().unwrap()).unwrap()
+        })
+        .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                json!({
+                    "id": "1",
+                    "type": "subscribe",
+                    "topic": "/market/match:BTC-USDT",
+                    "privateChannel": false,
+                    "response": true,
+                }),
+                json!({
+                    "id": "2",
+                    "type": "subscribe",
+                    "topic": "/spotMarket/level1:ETH-USDT",
+                    "privateChannel": false,
+                    "response": true,
+                }),
+            ]
+        );
+    }
+}
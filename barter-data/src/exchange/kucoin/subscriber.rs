@@ -0,0 +1,408 @@
+use crate::{
+    Identifier,
+    exchange::{Connector, kucoin::HTTP_BULLET_PUBLIC_URL_KUCOIN},
+    instrument::InstrumentData,
+    subscriber::{Subscribed, Subscriber, mapper::WebSocketSubMapper, subscribe_websocket},
+    subscription::{Subscription, SubscriptionKind},
+};
+use async_trait::async_trait;
+use barter_integration::{
+    error::SocketError,
+    protocol::{
+        StreamParser,
+        websocket::{WebSocket, WebSocketSerdeParser, connect},
+    },
+};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::debug;
+use url::Url;
+
+/// [`Kucoin`](super::Kucoin) [`Subscriber`] that requests a public connection token before
+/// connecting to the WebSocket server.
+///
+/// [`Kucoin`](super::Kucoin) WebSocket endpoints are dynamic, so the connection `Url` is built
+/// from the [`KucoinBulletResponse`] returned by the [`HTTP_BULLET_PUBLIC_URL_KUCOIN`] token
+/// endpoint, which also provides the server ping interval. The server then sends a welcome
+/// message, which must be received before subscribing.
+///
+/// Note that the token endpoint determines the WebSocket endpoint, so this [`Subscriber`] does
+/// not honour `override_url`.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinWebSocketSubscriber;
+
+#[async_trait]
+impl Subscriber for KucoinWebSocketSubscriber {
+    type SubMapper = WebSocketSubMapper;
+
+    async fn subscribe<Exchange, Instrument, Kind>(
+        subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+    ) -> Result<Subscribed<Instrument::Key>, SocketError>
+    where
+        Exchange: Connector + Send + Sync,
+        Kind: SubscriptionKind + Send + Sync,
+        Instrument: InstrumentData,
+        Subscription<Exchange, Instrument, Kind>:
+            Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+    {
+        // Define variables for logging ergonomics
+        let exchange = Exchange::ID;
+        let token_url = Url::parse(HTTP_BULLET_PUBLIC_URL_KUCOIN)?;
+        debug!(%exchange, %token_url, "requesting WebSocket connection token");
+
+        // Request public connection token & dynamic WebSocket endpoint
+        let bullet = reqwest::Client::new()
+            .post(token_url)
+            .send()
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SocketError::Http)?
+            .json::<KucoinBulletResponse>()
+            .await
+            .map_err(SocketError::Http)?
+            .into_bullet()?;
+
+        let url = bullet.websocket_url()?;
+        debug!(%exchange, ?subscriptions, "subscribing to WebSocket");
+
+        // Connect to exchange & wait for welcome message
+        let mut websocket = connect(url).await?;
+        await_welcome::<Exchange>(&mut websocket).await?;
+        debug!(%exchange, ?subscriptions, "connected to WebSocket");
+
+        // Ping this connection at the server provided interval
+        let subscribed =
+            subscribe_websocket::<Exchange, Instrument, Kind>(websocket, subscriptions).await?;
+
+        Ok(Subscribed {
+            ping_interval: bullet.ping_interval(),
+            ..subscribed
+        })
+    }
+}
+
+/// [`Kucoin`](super::Kucoin) REST response code indicating success.
+pub const SUCCESS_CODE_KUCOIN: &str = "200000";
+
+/// [`Kucoin`](super::Kucoin) public connection token response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/apply-connect-token/public-token-no-authentication-required->
+/// #### Success
+/// ```json
+/// {
+///     "code": "200000",
+///     "data": {
+///         "token": "2neAiuYvAU61ZDXANAGAsiL4-iAExhsBXZxftpOeh_55i3Ysy2q2LEsEWU64mdzUOPusi34M_wGoSf7iNyEWJ4aBZXpWhrmY9jKtqkdWoFa75w3istPvPtiYB9J6i9GjsxUuhPw3BlrzazF6ghq4L_u0MhKxG3x8TeN4aVbNiYo=.mvnekBb8DJegZIgYLs2FBQ==",
+///         "instanceServers": [
+///             {
+///                 "endpoint": "wss://ws-api-spot.kucoin.com/",
+///                 "encrypt": true,
+///                 "protocol": "websocket",
+///                 "pingInterval": 18000,
+///                 "pingTimeout": 10000
+///             }
+///         ]
+///     }
+/// }
+/// ```
+///
+/// #### Failure
+/// ```json
+/// {"code": "429000", "msg": "Too Many Requests"}
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct KucoinBulletResponse {
+    pub code: String,
+    #[serde(default)]
+    pub msg: Option<String>,
+    #[serde(default)]
+    pub data: Option<KucoinBullet>,
+}
+
+/// [`Kucoin`](super::Kucoin) public connection token and available WebSocket servers.
+///
+/// See [`KucoinBulletResponse`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KucoinBullet {
+    pub token: String,
+    pub instance_servers: Vec<KucoinInstanceServer>,
+}
+
+/// [`Kucoin`](super::Kucoin) WebSocket server that a connection token may be used with.
+///
+/// See [`KucoinBulletResponse`] for full raw payload examples.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct KucoinInstanceServer {
+    pub endpoint: String,
+    pub ping_interval: u64,
+}
+
+impl KucoinBulletResponse {
+    /// Extract the [`KucoinBullet`], returning an error if the response `code` is not
+    /// [`SUCCESS_CODE_KUCOIN`].
+    pub fn into_bullet(self) -> Result<KucoinBullet, SocketError> {
+        match (self.code.as_str(), self.data) {
+            (SUCCESS_CODE_KUCOIN, Some(bullet)) => Ok(bullet),
+            (code, _) => Err(SocketError::Subscribe(format!(
+                "received failure connection token response code: {code} with msg: {}",
+                self.msg.as_deref().unwrap_or_default()
+            ))),
+        }
+    }
+}
+
+impl KucoinBullet {
+    /// Construct the WebSocket connection [`Url`] from the first available instance server.
+    pub fn websocket_url(&self) -> Result<Url, SocketError> {
+        let server = self.instance_servers.first().ok_or_else(|| {
+            SocketError::Subscribe(
+                "Kucoin connection token response contained no instance servers".to_string(),
+            )
+        })?;
+
+        let mut url = Url::parse(&server.endpoint)?;
+        url.query_pairs_mut()
+            .append_pair("token", &self.token)
+            .append_pair(
+                "connectId",
+                &chrono::Utc::now().timestamp_millis().to_string(),
+            );
+
+        Ok(url)
+    }
+
+    /// Server ping interval of the first available instance server, if non-zero.
+    pub fn ping_interval(&self) -> Option<Duration> {
+        self.instance_servers
+            .first()
+            .map(|server| Duration::from_millis(server.ping_interval))
+            .filter(|interval| !interval.is_zero())
+    }
+}
+
+/// [`Kucoin`](super::Kucoin) welcome message sent once a WebSocket connection is established.
+///
+/// ### Raw Payload Examples
+/// ```json
+/// {"id": "hQvf8jkno", "type": "welcome"}
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum KucoinWelcome {
+    Welcome { id: String },
+}
+
+/// Wait for the [`KucoinWelcome`] message, which [`Kucoin`](super::Kucoin) requires before any
+/// subscription requests are sent.
+async fn await_welcome<Exchange>(websocket: &mut WebSocket) -> Result<(), SocketError>
+where
+    Exchange: Connector,
+{
+    let timeout = Exchange::subscription_timeout();
+
+    tokio::time::timeout(timeout, async {
+        loop {
+            let message = websocket.next().await.ok_or_else(|| {
+                SocketError::Subscribe("WebSocket stream terminated unexpectedly".to_string())
+            })?;
+
+            match <WebSocketSerdeParser as StreamParser<KucoinWelcome>>::parse(message) {
+                Some(Ok(welcome)) => {
+                    debug!(exchange = %Exchange::ID, ?welcome, "received WebSocket welcome");
+                    break Ok(());
+                }
+                Some(Err(SocketError::Terminated(close_frame))) => {
+                    break Err(SocketError::Subscribe(format!(
+                        "received WebSocket CloseFrame: {close_frame}"
+                    )));
+                }
+                _ => continue,
+            }
+        }
+    })
+    .await
+    .map_err(|_| {
+        SocketError::Subscribe(format!("WebSocket welcome timeout reached: {timeout:?}"))
+    })?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bullet(instance_servers: Vec<KucoinInstanceServer>) -> KucoinBullet {
+        KucoinBullet {
+            token: "token".to_string(),
+            instance_servers,
+        }
+    }
+
+    fn instance_server(ping_interval: u64) -> KucoinInstanceServer {
+        KucoinInstanceServer {
+            endpoint: "wss://ws-api-spot.kucoin.com/".to_string(),
+            ping_interval,
+        }
+    }
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kucoin_bullet_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: KucoinBulletResponse,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: successful connection token response
+                    input: r#"
+                    {
+                        "code": "200000",
+                        "data": {
+                            "token": "2neAiuYvAU61ZDXANAGAsiL4",
+                            "instanceServers": [
+                                {
+                                    "endpoint": "wss://ws-api-spot.kucoin.com/",
+                                    "encrypt": true,
+                                    "protocol": "websocket",
+                                    "pingInterval": 18000,
+                                    "pingTimeout": 10000
+                                }
+                            ]
+                        }
+                    }
+                    "#,
+                    expected: KucoinBulletResponse {
+                        code: "200000".to_string(),
+                        msg: None,
+                        data: Some(KucoinBullet {
+                            token: "2neAiuYvAU61ZDXANAGAsiL4".to_string(),
+                            instance_servers: vec![instance_server(18000)],
+                        }),
+                    },
+                },
+                TestCase {
+                    // TC1: failed connection token response
+                    input: r#"{"code": "429000", "msg": "Too Many Requests"}"#,
+                    expected: KucoinBulletResponse {
+                        code: "429000".to_string(),
+                        msg: Some("Too Many Requests".to_string()),
+                        data: None,
+                    },
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KucoinBulletResponse>(test.input).unwrap();
+                assert_eq!(actual, test.expected, "TC{} failed", index);
+            }
+        }
+    }
+
+    #[test]
+    fn test_kucoin_bullet_response_into_bullet() {
+        struct TestCase {
+            input: KucoinBulletResponse,
+            expected: Option<KucoinBullet>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: success code with data
+                input: KucoinBulletResponse {
+                    code: SUCCESS_CODE_KUCOIN.to_string(),
+                    msg: None,
+                    data: Some(bullet(vec![instance_server(18000)])),
+                },
+                expected: Some(bullet(vec![instance_server(18000)])),
+            },
+            TestCase {
+                // TC1: failure code
+                input: KucoinBulletResponse {
+                    code: "429000".to_string(),
+                    msg: Some("Too Many Requests".to_string()),
+                    data: None,
+                },
+                expected: None,
+            },
+            TestCase {
+                // TC2: failure code is rejected even if data is present
+                input: KucoinBulletResponse {
+                    code: "400100".to_string(),
+                    msg: None,
+                    data: Some(bullet(vec![instance_server(18000)])),
+                },
+                expected: None,
+            },
+            TestCase {
+                // TC3: success code without data
+                input: KucoinBulletResponse {
+                    code: SUCCESS_CODE_KUCOIN.to_string(),
+                    msg: None,
+                    data: None,
+                },
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input.into_bullet().ok();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_kucoin_bullet_websocket_url() {
+        struct TestCase {
+            input: KucoinBullet,
+            expected: Option<(&'static str, &'static str)>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: Url built from first instance server with token
+                input: bullet(vec![instance_server(18000)]),
+                expected: Some(("ws-api-spot.kucoin.com", "token")),
+            },
+            TestCase {
+                // TC1: no instance servers available
+                input: bullet(vec![]),
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.input.websocket_url().ok().map(|url| {
+                let token = url
+                    .query_pairs()
+                    .find(|(key, _)| key == "token")
+                    .map(|(_, value)| value.into_owned());
+                (url.host_str().map(str::to_string), token)
+            });
+
+            let expected = test
+                .expected
+                .map(|(host, token)| (Some(host.to_string()), Some(token.to_string())));
+
+            assert_eq!(actual, expected, "TC{} failed", index);
+        }
+    }
+
+    #[test]
+    fn test_kucoin_bullet_ping_interval() {
+        assert_eq!(
+            bullet(vec![instance_server(30000)]).ping_interval(),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(bullet(vec![instance_server(0)]).ping_interval(), None);
+        assert_eq!(bullet(vec![]).ping_interval(), None);
+    }
+}
@@ -0,0 +1,143 @@
+use barter_integration::{Validator, error::SocketError};
+use serde::{Deserialize, Serialize};
+
+/// [`Kucoin`](super::Kucoin) WebSocket subscription response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.kucoin.com/docs/websocket/basic-info/subscribe/introduction>
+/// #### Subscription Success
+/// ```json
+/// {
+///     "id": "1545910660739",
+///     "type": "ack"
+/// }
+/// ```
+///
+/// #### Subscription Failure
+/// ```json
+/// {
+///     "id": "1545910660739",
+///     "type": "error",
+///     "code": 404,
+///     "data": "topic /market/match:GIBBERISH-USDT is not found"
+/// }
+/// ```
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum KucoinSubResponse {
+    Ack { id: String },
+    Error { code: i64, data: String },
+}
+
+impl Validator for KucoinSubResponse {
+    fn validate(self) -> Result<Self, SocketError>
+    where
+        Self: Sized,
+    {
+        match &self {
+            KucoinSubResponse::Ack { .. } => Ok(self),
+            KucoinSubResponse::Error { code, data } => Err(SocketError::Subscribe(format!(
+                "received failure subscription response code: {code} with data: {data}",
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kucoin_sub_response() {
+            struct TestCase {
+                input: &'static str,
+                expected: Result<KucoinSubResponse, SocketError>,
+            }
+
+            let cases = vec![
+                TestCase {
+                    // TC0: input response is Ack
+                    input: r#"{"id": "1545910660739", "type": "ack"}"#,
+                    expected: Ok(KucoinSubResponse::Ack {
+                        id: "1545910660739".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC1: input response is failed subscription
+                    input: r#"
+                    {
+                        "id": "1545910660739",
+                        "type": "error",
+                        "code": 404,
+                        "data": "topic /market/match:GIBBERISH-USDT is not found"
+                    }
+                    "#,
+                    expected: Ok(KucoinSubResponse::Error {
+                        code: 404,
+                        data: "topic /market/match:GIBBERISH-USDT is not found".to_string(),
+                    }),
+                },
+                TestCase {
+                    // TC2: input is a welcome message, not a subscription response
+                    input: r#"{"id": "hQvf8jkno", "type": "welcome"}"#,
+                    expected: Err(SocketError::Unsupported {
+                        entity: "".to_string(),
+                        item: "".to_string(),
+                    }),
+                },
+            ];
+
+            for (index, test) in cases.into_iter().enumerate() {
+                let actual = serde_json::from_str::<KucoinSubResponse>(test.input);
+                match (actual, test.expected) {
+                    (Ok(actual), Ok(expected)) => {
+                        assert_eq!(actual, expected, "TC{} failed", index)
+                    }
+                    (Err(_), Err(_)) => {
+                        // Test passed
+                    }
+                    (actual, expected) => {
+                        // Test failed
+                        panic!(
+                            "TC{index} failed because actual != expected. \nActual: {actual:?}\nExpected: {expected:?}\n"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate_kucoin_sub_response() {
+        struct TestCase {
+            input_response: KucoinSubResponse,
+            is_valid: bool,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: input response is successful subscription
+                input_response: KucoinSubResponse::Ack {
+                    id: "1545910660739".to_string(),
+                },
+                is_valid: true,
+            },
+            TestCase {
+                // TC1: input response is failed subscription
+                input_response: KucoinSubResponse::Error {
+                    code: 404,
+                    data: "topic /market/match:GIBBERISH-USDT is not found".to_string(),
+                },
+                is_valid: false,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input_response.validate().is_ok();
+            assert_eq!(actual, test.is_valid, "TestCase {} failed", index);
+        }
+    }
+}
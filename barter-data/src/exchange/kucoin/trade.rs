@@ -0,0 +1,109 @@
+use super::message::KucoinMessage;
+use crate::{
+    event::{MarketEvent, MarketIter},
+    subscription::trade::PublicTrade,
+};
+use barter_instrument::{Side, exchange::ExchangeId};
+use barter_integration::de::datetime_utc_from_epoch_duration;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// Terse type alias for a [`Kucoin`](super::Kucoin) real-time trades WebSocket message.
+pub type KucoinTrade = KucoinMessage<KucoinTradeInner>;
+
+/// [`Kucoin`](super::Kucoin) real-time trade WebSocket message.
+///
+/// See [`KucoinMessage`] for full raw payload examples.
+///
+/// See docs: <https://www.kucoin.com/docs/websocket/spot-trading/public-channels/match-execution-data>
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct KucoinTradeInner {
+    #[serde(rename = "tradeId")]
+    pub id: String,
+    #[serde(deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "size", deserialize_with = "barter_integration::de::de_str")]
+    pub amount: f64,
+    pub side: Side,
+    #[serde(deserialize_with = "de_str_u64_epoch_ns_as_datetime_utc")]
+    pub time: DateTime<Utc>,
+}
+
+impl<InstrumentKey> From<(ExchangeId, InstrumentKey, KucoinTrade)>
+    for MarketIter<InstrumentKey, PublicTrade>
+{
+    fn from((exchange, instrument, trade): (ExchangeId, InstrumentKey, KucoinTrade)) -> Self {
+        Self(vec![Ok(MarketEvent {
+            time_exchange: trade.data.time,
+            time_received: Utc::now(),
+            exchange,
+            instrument,
+            kind: PublicTrade {
+                id: trade.data.id,
+                price: trade.data.price,
+                amount: trade.data.amount,
+                side: trade.data.side,
+            },
+        })])
+    }
+}
+
+/// Deserialize a [`KucoinTradeInner`] "time" String (eg/ "1652999937016234900") representing
+/// epoch nanoseconds as a [`DateTime<Utc>`].
+fn de_str_u64_epoch_ns_as_datetime_utc<'de, D>(deserializer: D) -> Result<DateTime<Utc>, D::Error>
+where
+    D: serde::de::Deserializer<'de>,
+{
+    barter_integration::de::de_str(deserializer)
+        .map(|epoch_ns| datetime_utc_from_epoch_duration(Duration::from_nanos(epoch_ns)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::subscription::SubscriptionId;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_kucoin_message_trade() {
+            let input = r#"
+            {
+                "type": "message",
+                "topic": "/market/match:BTC-USDT",
+                "subject": "trade.l3match",
+                "data": {
+                    "makerOrderId": "6287c3015c27f000017d0c2f",
+                    "price": "31078.80000000000000000000",
+                    "sequence": "11623989",
+                    "side": "buy",
+                    "size": "0.00015200000000000000",
+                    "symbol": "BTC-USDT",
+                    "takerOrderId": "6287c3015c27f000017d0c32",
+                    "time": "1652999937016234900",
+                    "tradeId": "6287c3015c27f000017d0c33",
+                    "type": "match"
+                }
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<KucoinTrade>(input).unwrap(),
+                KucoinTrade {
+                    subscription_id: SubscriptionId::from("/market/match|BTC-USDT"),
+                    data: KucoinTradeInner {
+                        id: "6287c3015c27f000017d0c33".to_string(),
+                        price: 31078.8,
+                        amount: 0.000152,
+                        side: Side::Buy,
+                        time: datetime_utc_from_epoch_duration(Duration::from_nanos(
+                            1652999937016234900
+                        )),
+                    },
+                }
+            );
+        }
+    }
+}
@@ -8,9 +8,16 @@ use crate::{
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{Validator, error::SocketError, protocol::websocket::WsMessage};
 use serde::{Deserialize, Serialize, de::DeserializeOwned};
-use std::{fmt::Debug, time::Duration};
+use std::{fmt::Debug, num::NonZeroUsize, time::Duration};
 use url::Url;
 
+#[cfg(feature = "url-override")]
+use fnv::FnvHashMap;
+#[cfg(feature = "url-override")]
+use parking_lot::RwLock;
+#[cfg(feature = "url-override")]
+use std::sync::LazyLock;
+
 /// `BinanceSpot` & `BinanceFuturesUsd` [`Connector`] and [`StreamSelector`] implementations.
 pub mod binance;
 
@@ -30,9 +37,15 @@ pub mod coinbase;
 /// implementations.
 pub mod gateio;
 
+/// `Hyperliquid` [`Connector`] and [`StreamSelector`] implementations.
+pub mod hyperliquid;
+
 /// `Kraken` [`Connector`] and [`StreamSelector`] implementations.
 pub mod kraken;
 
+/// `Kucoin` [`Connector`] and [`StreamSelector`] implementations.
+pub mod kucoin;
+
 /// `Okx` [`Connector`] and [`StreamSelector`] implementations.
 pub mod okx;
 
@@ -117,6 +130,14 @@ where
         None
     }
 
+    /// Defines the [`SubscribeRateLimit`] the [`Self::Subscriber`] must respect when sending the
+    /// [`WsMessage`] subscription payloads generated by [`Self::requests`].
+    ///
+    /// Defaults to `None`, meaning that all subscription payloads are sent immediately.
+    fn subscribe_rate_limit() -> Option<SubscribeRateLimit> {
+        None
+    }
+
     /// Defines how to translate a collection of [`ExchangeSub`]s into the [`WsMessage`]
     /// subscription payloads sent to the exchange server.
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage>;
@@ -135,6 +156,41 @@ where
     }
 }
 
+/// Process wide [`Connector::url`] overrides, keyed by [`ExchangeId`].
+#[cfg(feature = "url-override")]
+static URL_OVERRIDES: LazyLock<RwLock<FnvHashMap<ExchangeId, Url>>> =
+    LazyLock::new(Default::default);
+
+/// Override the [`Connector::url`] of the provided [`ExchangeId`] for every subsequent connection
+/// made by the [`WebSocketSubscriber`](crate::subscriber::WebSocketSubscriber) in this process.
+///
+/// Intended for testing against a local mock exchange server, in particular via
+/// [`DynamicStreams`](crate::streams::builder::dynamic::DynamicStreams), which constructs the
+/// production [`Connector`]s internally. Only available with the test only `url-override`
+/// feature.
+///
+/// Note that [`Kucoin`](kucoin::Kucoin) connections are not affected, since the
+/// [`KucoinWebSocketSubscriber`](kucoin::subscriber::KucoinWebSocketSubscriber) connects to the
+/// endpoint provided by its connection token response.
+#[cfg(feature = "url-override")]
+pub fn override_url(exchange: ExchangeId, url: Url) {
+    URL_OVERRIDES.write().insert(exchange, url);
+}
+
+/// Determine the [`Url`] to connect to for the provided [`Connector`], which is the
+/// [`Connector::url`] unless it has been overridden via `override_url`.
+pub(crate) fn connector_url<Exchange>() -> Result<Url, SocketError>
+where
+    Exchange: Connector,
+{
+    #[cfg(feature = "url-override")]
+    if let Some(url) = URL_OVERRIDES.read().get(&Exchange::ID) {
+        return Ok(url.clone());
+    }
+
+    Exchange::url()
+}
+
 /// Used when an exchange has servers different
 /// [`InstrumentKind`](barter_instrument::instrument::kind::InstrumentKind) market data on distinct servers,
 /// allowing all the [`Connector`] logic to be identical apart from what this trait provides.
@@ -155,3 +211,71 @@ pub struct PingInterval {
     pub interval: tokio::time::Interval,
     pub ping: fn() -> WsMessage,
 }
+
+/// Defines the maximum number of subscription [`WsMessage`]s that can be sent to an exchange
+/// server over a single connection within an `interval` - used to pace the subscription
+/// payloads of large `Subscription` batches.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct SubscribeRateLimit {
+    pub max_messages: NonZeroUsize,
+    pub interval: Duration,
+}
+
+impl SubscribeRateLimit {
+    /// Determines if the subscription [`WsMessage`] at the provided send order `index` must wait
+    /// for the next `interval` before being sent.
+    pub fn requires_wait(&self, index: usize) -> bool {
+        index > 0 && index % self.max_messages == 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_subscribe_rate_limit_requires_wait() {
+        struct TestCase {
+            input: usize,
+            expected: bool,
+        }
+
+        let rate_limit = SubscribeRateLimit {
+            max_messages: NonZeroUsize::new(2).unwrap(),
+            interval: Duration::from_secs(1),
+        };
+
+        let tests = vec![
+            TestCase {
+                // TC0: first message is sent immediately
+                input: 0,
+                expected: false,
+            },
+            TestCase {
+                // TC1: second message is within the first interval
+                input: 1,
+                expected: false,
+            },
+            TestCase {
+                // TC2: third message must wait for the second interval
+                input: 2,
+                expected: true,
+            },
+            TestCase {
+                // TC3: fourth message is within the second interval
+                input: 3,
+                expected: false,
+            },
+            TestCase {
+                // TC4: fifth message must wait for the third interval
+                input: 4,
+                expected: true,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = rate_limit.requires_wait(test.input);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
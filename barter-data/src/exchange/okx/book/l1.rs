@@ -0,0 +1,215 @@
+use super::OkxLevel;
+use crate::{
+    books::Level,
+    event::{MarketEvent, MarketIter},
+    exchange::okx::{
+        contract::{OkxContract, OkxContractAmounts},
+        trade::OkxMessage,
+    },
+    subscription::book::OrderBookL1,
+};
+use barter_instrument::exchange::ExchangeId;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Terse type alias for an [`Okx`](super::super::Okx) real-time OrderBook Level1 (top of books)
+/// WebSocket message.
+pub type OkxOrderBooksL1 = OkxMessage<OkxOrderBookL1>;
+
+/// [`Okx`](super::super::Okx) real-time OrderBook Level1 (top of books) message.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "bbo-tbt",
+///     "instId": "BCH-USDT-SWAP"
+///   },
+///   "data": [
+///     {
+///       "asks": [["111.06", "55154", "0", "2"]],
+///       "bids": [["111.05", "57745", "0", "2"]],
+///       "ts": "1670324386802",
+///       "seqId": 363996337
+///     }
+///   ]
+/// }
+/// ```
+///
+/// Note that the level amounts of a derivative instrument are a number of contracts, which the
+/// [`OkxContractTransformer`](crate::exchange::okx::contract::OkxContractTransformer) converts
+/// into base asset amounts.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxOrderBookL1 {
+    pub bids: Vec<OkxLevel>,
+    pub asks: Vec<OkxLevel>,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+    #[serde(rename = "seqId")]
+    pub sequence: i64,
+}
+
+impl OkxContractAmounts for OkxOrderBookL1 {
+    fn into_base_amounts(self, contract: &OkxContract) -> Option<Self> {
+        let into_base_amount = |level: OkxLevel| {
+            Some(OkxLevel {
+                amount: contract.base_amount_decimal(level.amount, level.price)?,
+                ..level
+            })
+        };
+
+        Some(Self {
+            bids: self
+                .bids
+                .into_iter()
+                .map(into_base_amount)
+                .collect::<Option<_>>()?,
+            asks: self
+                .asks
+                .into_iter()
+                .map(into_base_amount)
+                .collect::<Option<_>>()?,
+            ..self
+        })
+    }
+}
+
+impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, OkxOrderBooksL1)>
+    for MarketIter<InstrumentKey, OrderBookL1>
+{
+    fn from((exchange, instrument, books): (ExchangeId, InstrumentKey, OkxOrderBooksL1)) -> Self {
+        books
+            .data
+            .into_iter()
+            .map(|book| {
+                Ok(MarketEvent {
+                    time_exchange: book.time,
+                    time_received: Utc::now(),
+                    exchange,
+                    instrument: instrument.clone(),
+                    kind: OrderBookL1 {
+                        last_update_time: book.time,
+                        best_bid: book.bids.first().copied().map(Level::from),
+                        best_ask: book.asks.first().copied().map(Level::from),
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{de::datetime_utc_from_epoch_duration, subscription::SubscriptionId};
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_order_books_l1() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "bbo-tbt",
+                    "instId": "BCH-USDT-SWAP"
+                },
+                "data": [
+                    {
+                        "asks": [["111.06", "55154", "0", "2"]],
+                        "bids": [["111.05", "57745", "0", "2"]],
+                        "ts": "1670324386802",
+                        "seqId": 363996337
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxOrderBooksL1>(input).unwrap(),
+                OkxOrderBooksL1 {
+                    subscription_id: SubscriptionId::from("bbo-tbt|BCH-USDT-SWAP"),
+                    data: vec![OkxOrderBookL1 {
+                        bids: vec![OkxLevel {
+                            price: dec!(111.05),
+                            amount: dec!(57745),
+                        }],
+                        asks: vec![OkxLevel {
+                            price: dec!(111.06),
+                            amount: dec!(55154),
+                        }],
+                        time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                            1670324386802
+                        )),
+                        sequence: 363996337,
+                    }],
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_okx_order_books_l1_to_market_iter() {
+        let time = datetime_utc_from_epoch_duration(Duration::from_millis(1670324386802));
+        let input = OkxOrderBooksL1 {
+            subscription_id: SubscriptionId::from("bbo-tbt|BCH-USDT-SWAP"),
+            data: vec![OkxOrderBookL1 {
+                bids: vec![OkxLevel {
+                    price: dec!(111.05),
+                    amount: dec!(57745),
+                }],
+                asks: vec![],
+                time,
+                sequence: 363996337,
+            }],
+        };
+
+        let actual =
+            MarketIter::<&str, OrderBookL1>::from((ExchangeId::Okx, "bch_usdt_perp", input))
+                .0
+                .into_iter()
+                .map(|event| event.unwrap().kind)
+                .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![OrderBookL1 {
+                last_update_time: time,
+                best_bid: Some(Level::new(dec!(111.05), dec!(57745))),
+                best_ask: None,
+            }]
+        );
+    }
+
+    #[test]
+    fn test_okx_order_book_l1_into_base_amounts() {
+        let input = OkxOrderBookL1 {
+            bids: vec![OkxLevel {
+                price: dec!(111.05),
+                amount: dec!(57745),
+            }],
+            asks: vec![OkxLevel {
+                price: dec!(111.06),
+                amount: dec!(55154),
+            }],
+            time: DateTime::<Utc>::MIN_UTC,
+            sequence: 363996337,
+        };
+
+        let actual = input
+            .into_base_amounts(&OkxContract {
+                value: dec!(0.1),
+                inverse: false,
+            })
+            .unwrap();
+
+        assert_eq!(actual.bids[0].amount, dec!(5774.5));
+        assert_eq!(actual.asks[0].amount, dec!(5515.4));
+    }
+}
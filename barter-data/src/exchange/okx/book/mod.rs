@@ -0,0 +1,68 @@
+use crate::books::Level;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+/// Level 1 OrderBook types (top of books).
+pub mod l1;
+
+/// [`Okx`](super::Okx) OrderBook level.
+///
+/// #### Raw Payload Examples
+/// Format: [price, quantity, deprecated liquidated orders, number of orders]
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+/// ```json
+/// ["8476.98", "415", "0", "13"]
+/// ```
+#[derive(Clone, Copy, PartialEq, PartialOrd, Debug, Serialize)]
+pub struct OkxLevel {
+    pub price: Decimal,
+    pub amount: Decimal,
+}
+
+impl<'de> Deserialize<'de> for OkxLevel {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let (price, amount, _, _) = <(
+            Decimal,
+            Decimal,
+            serde::de::IgnoredAny,
+            serde::de::IgnoredAny,
+        )>::deserialize(deserializer)?;
+
+        Ok(Self { price, amount })
+    }
+}
+
+impl From<OkxLevel> for Level {
+    fn from(level: OkxLevel) -> Self {
+        Self {
+            price: level.price,
+            amount: level.amount,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    mod de {
+        use super::*;
+        use rust_decimal_macros::dec;
+
+        #[test]
+        fn test_okx_level() {
+            let input = r#"["8476.98", "415", "0", "13"]"#;
+            assert_eq!(
+                serde_json::from_str::<OkxLevel>(input).unwrap(),
+                OkxLevel {
+                    price: dec!(8476.98),
+                    amount: dec!(415),
+                },
+            )
+        }
+    }
+}
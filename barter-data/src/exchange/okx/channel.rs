@@ -1,7 +1,9 @@
 use super::Okx;
 use crate::{
     Identifier,
-    subscription::{Subscription, trade::PublicTrades},
+    subscription::{
+        Subscription, book::OrderBooksL1, liquidation::Liquidations, trade::PublicTrades,
+    },
 };
 use serde::Serialize;
 
@@ -17,6 +19,19 @@ impl OkxChannel {
     ///
     /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-trades-channel>
     pub const TRADES: Self = Self("trades");
+
+    /// [`Okx`] real-time OrderBook Level1 (top of books) channel, pushed tick-by-tick.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#order-book-trading-market-data-ws-order-book-channel>
+    pub const ORDER_BOOK_L1: Self = Self("bbo-tbt");
+
+    /// [`Okx`] real-time liquidation orders channel.
+    ///
+    /// Note that this channel is scoped by `instType`, and pushes liquidations for every
+    /// instrument of that type. Spot instruments are subscribed to via the `MARGIN` instType.
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+    pub const LIQUIDATIONS: Self = Self("liquidation-orders");
 }
 
 impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, PublicTrades> {
@@ -25,6 +40,18 @@ impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, Public
     }
 }
 
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, OrderBooksL1> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::ORDER_BOOK_L1
+    }
+}
+
+impl<Instrument> Identifier<OkxChannel> for Subscription<Okx, Instrument, Liquidations> {
+    fn id(&self) -> OkxChannel {
+        OkxChannel::LIQUIDATIONS
+    }
+}
+
 impl AsRef<str> for OkxChannel {
     fn as_ref(&self) -> &str {
         self.0
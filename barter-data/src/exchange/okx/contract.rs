@@ -0,0 +1,632 @@
+use super::{
+    Okx,
+    market::{OkxInstrumentType, OkxMarket},
+    trade::OkxMessage,
+};
+use crate::{
+    Identifier,
+    error::DataError,
+    event::{MarketEvent, MarketIter},
+    exchange::Connector,
+    subscription::{Map, SubscriptionKind},
+    transformer::ExchangeTransformer,
+};
+use async_trait::async_trait;
+use barter_instrument::exchange::ExchangeId;
+use barter_integration::{
+    Transformer, error::SocketError, protocol::websocket::WsMessage, subscription::SubscriptionId,
+};
+use fnv::FnvHashMap;
+use itertools::Itertools;
+use parking_lot::Mutex;
+use rust_decimal::{Decimal, prelude::ToPrimitive};
+use serde::{Deserialize, Deserializer, Serialize};
+use smol_str::SmolStr;
+use std::{
+    marker::PhantomData,
+    sync::LazyLock,
+    time::{Duration, Instant},
+};
+use tokio::sync::mpsc;
+use tracing::debug;
+
+/// [`Okx`] public instruments url, used to fetch derivative contract specifications.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+pub const HTTP_INSTRUMENTS_URL_OKX: &str = "https://www.okx.com/api/v5/public/instruments";
+
+/// Duration after which cached [`Okx`] contract specifications are re-fetched when the next
+/// [`OkxContracts`] are requested (eg/ on MarketStream reconnection).
+pub const CONTRACTS_REFRESH_INTERVAL_OKX: Duration = Duration::from_secs(3600);
+
+/// Process wide cache of fetched [`Okx`] contract specifications, shared by every [`Okx`]
+/// MarketStream (re)connection to avoid re-fetching the instruments on every reconnect.
+static CONTRACTS_CACHE: LazyLock<Mutex<FnvHashMap<OkxInstrumentsQuery, CachedContracts>>> =
+    LazyLock::new(Default::default);
+
+#[derive(Debug)]
+struct CachedContracts {
+    fetched: Instant,
+    contracts: FnvHashMap<SmolStr, OkxContract>,
+}
+
+/// [`Okx`] derivative contract specification used to normalise amounts denominated in contracts
+/// (eg/ SWAP, FUTURES & OPTION `sz`) into base asset amounts, consistent with other exchanges.
+///
+/// For linear contracts the contract value is denominated in the base asset, whereas for inverse
+/// contracts (eg/ "BTC-USD-SWAP") it is denominated in the quote asset.
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+pub struct OkxContract {
+    /// Contract value multiplied by the contract multiplier (ie/ `ctVal * ctMult`).
+    pub value: Decimal,
+    /// True if the contract value is denominated in the quote asset.
+    pub inverse: bool,
+}
+
+impl OkxContract {
+    /// Convert the provided number of contracts traded at the provided price into a base asset
+    /// amount.
+    ///
+    /// Returns `None` if the contract value cannot be represented as an `f64`, or if an inverse
+    /// contract price is not positive.
+    pub fn base_amount(&self, contracts: f64, price: f64) -> Option<f64> {
+        let amount = contracts * self.value.to_f64()?;
+        match (self.inverse, price > 0.0) {
+            (false, _) => Some(amount),
+            (true, true) => Some(amount / price),
+            (true, false) => None,
+        }
+    }
+
+    /// Convert the provided number of contracts at the provided price level into a base asset
+    /// amount.
+    ///
+    /// Returns `None` if the conversion overflows, or if an inverse contract price is not
+    /// positive.
+    pub fn base_amount_decimal(&self, contracts: Decimal, price: Decimal) -> Option<Decimal> {
+        let amount = contracts.checked_mul(self.value)?;
+        match (self.inverse, price > Decimal::ZERO) {
+            (false, _) => Some(amount),
+            (true, true) => amount.checked_div(price),
+            (true, false) => None,
+        }
+    }
+}
+
+/// [`Okx`] market data containing amounts that are denominated in contracts for derivative
+/// instruments.
+pub trait OkxContractAmounts: Sized {
+    /// Convert any contract denominated amounts into base asset amounts.
+    ///
+    /// Returns `None` if any amount cannot be converted (see [`OkxContract::base_amount`]).
+    fn into_base_amounts(self, contract: &OkxContract) -> Option<Self>;
+}
+
+/// [`OkxContract`] specifications of the subscribed [`Okx`] derivative instruments, keyed by
+/// `instId`.
+///
+/// Spot & margin instruments are not included, since their amounts are already base asset
+/// amounts.
+#[derive(Clone, Eq, PartialEq, Debug, Default, Deserialize, Serialize)]
+pub struct OkxContracts(pub FnvHashMap<SmolStr, OkxContract>);
+
+impl FromIterator<(SmolStr, OkxContract)> for OkxContracts {
+    fn from_iter<Iter>(iter: Iter) -> Self
+    where
+        Iter: IntoIterator<Item = (SmolStr, OkxContract)>,
+    {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl OkxContracts {
+    /// Fetch the [`OkxContract`] of every derivative instrument associated with the provided
+    /// `channel|instId` [`SubscriptionId`]s, using cached specifications that were fetched within
+    /// the [`CONTRACTS_REFRESH_INTERVAL_OKX`].
+    pub async fn fetch(subscription_ids: Vec<SubscriptionId>) -> Result<Self, DataError> {
+        let markets = subscription_ids
+            .into_iter()
+            .filter_map(|subscription_id| {
+                let market = OkxMarket(SmolStr::new(subscription_id.as_ref().split_once('|')?.1));
+                OkxInstrumentsQuery::new(&market).map(|query| (subscription_id, market, query))
+            })
+            .collect::<Vec<_>>();
+
+        if markets.is_empty() {
+            return Ok(Self::default());
+        }
+
+        let queries = markets
+            .iter()
+            .map(|(_, _, query)| query.clone())
+            .unique()
+            .collect::<Vec<_>>();
+
+        // Determine which queries are stale, without holding the cache lock during requests
+        let stale = {
+            let cache = CONTRACTS_CACHE.lock();
+            queries
+                .into_iter()
+                .filter(|query| {
+                    cache.get(query).is_none_or(|cached| {
+                        cached.fetched.elapsed() >= CONTRACTS_REFRESH_INTERVAL_OKX
+                    })
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let mut fetched = Vec::with_capacity(stale.len());
+        for query in stale {
+            let contracts = query.fetch().await?;
+            fetched.push((query, contracts));
+        }
+
+        let mut cache = CONTRACTS_CACHE.lock();
+        for (query, contracts) in fetched {
+            cache.insert(
+                query,
+                CachedContracts {
+                    fetched: Instant::now(),
+                    contracts,
+                },
+            );
+        }
+
+        markets
+            .into_iter()
+            .map(|(subscription_id, market, query)| {
+                cache
+                    .get(&query)
+                    .and_then(|cached| cached.contracts.get(&market.0))
+                    .map(|contract| (market.0, *contract))
+                    .ok_or(DataError::ContractSpecMissing(subscription_id))
+            })
+            .collect()
+    }
+
+    /// Find the [`OkxContract`] associated with the provided `instId`, if it is a derivative.
+    pub fn find(&self, instrument: &str) -> Option<&OkxContract> {
+        self.0.get(instrument)
+    }
+}
+
+/// [`Okx`] public instruments request parameters.
+///
+/// Options instruments must be requested per `instFamily` (eg/ "BTC-USD").
+#[derive(Clone, Eq, PartialEq, Hash, Debug)]
+struct OkxInstrumentsQuery {
+    instrument_type: OkxInstrumentType,
+    instrument_family: Option<SmolStr>,
+}
+
+impl OkxInstrumentsQuery {
+    fn new(market: &OkxMarket) -> Option<Self> {
+        let instrument_type = market.instrument_type();
+        let instrument_family = match instrument_type {
+            OkxInstrumentType::Spot | OkxInstrumentType::Margin => return None,
+            OkxInstrumentType::Swap | OkxInstrumentType::Futures => None,
+            OkxInstrumentType::Option => {
+                let mut segments = market.0.split('-');
+                Some(SmolStr::from_iter([
+                    segments.next()?,
+                    "-",
+                    segments.next()?,
+                ]))
+            }
+        };
+
+        Some(Self {
+            instrument_type,
+            instrument_family,
+        })
+    }
+
+    async fn fetch(&self) -> Result<FnvHashMap<SmolStr, OkxContract>, DataError> {
+        let mut url = format!(
+            "{HTTP_INSTRUMENTS_URL_OKX}?instType={}",
+            self.instrument_type.as_str()
+        );
+        if let Some(instrument_family) = &self.instrument_family {
+            url.push_str("&instFamily=");
+            url.push_str(instrument_family);
+        }
+        debug!(exchange = %Okx::ID, %url, "fetching contract specifications");
+
+        let response = reqwest::get(url)
+            .await
+            .and_then(reqwest::Response::error_for_status)
+            .map_err(SocketError::Http)?
+            .json::<OkxInstrumentsResponse>()
+            .await
+            .map_err(SocketError::Http)?;
+
+        if response.code != "0" {
+            return Err(DataError::from(SocketError::Exchange(format!(
+                "failed to fetch Okx instruments: code={}, msg={}",
+                response.code, response.msg
+            ))));
+        }
+
+        Ok(response
+            .data
+            .into_iter()
+            .filter_map(|spec| {
+                let contract = spec.contract()?;
+                Some((spec.instrument, contract))
+            })
+            .collect())
+    }
+}
+
+/// [`Okx`] public instruments response.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+/// ```json
+/// {
+///   "code": "0",
+///   "msg": "",
+///   "data": [
+///     {
+///       "instType": "SWAP",
+///       "instId": "BTC-USDT-SWAP",
+///       "instFamily": "BTC-USDT",
+///       "ctVal": "0.01",
+///       "ctMult": "1",
+///       "ctValCcy": "BTC",
+///       "settleCcy": "USDT",
+///       "state": "live"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+pub struct OkxInstrumentsResponse {
+    pub code: SmolStr,
+    pub msg: String,
+    pub data: Vec<OkxInstrumentSpec>,
+}
+
+/// [`Okx`] instrument specification, see [`OkxInstrumentsResponse`] for raw payload examples.
+#[derive(Clone, PartialEq, Debug, Deserialize)]
+pub struct OkxInstrumentSpec {
+    #[serde(rename = "instId")]
+    pub instrument: SmolStr,
+    #[serde(
+        rename = "ctVal",
+        default,
+        deserialize_with = "de_okx_optional_decimal"
+    )]
+    pub contract_value: Option<Decimal>,
+    #[serde(
+        rename = "ctMult",
+        default,
+        deserialize_with = "de_okx_optional_decimal"
+    )]
+    pub contract_multiplier: Option<Decimal>,
+    #[serde(rename = "ctValCcy", default)]
+    pub contract_value_currency: SmolStr,
+}
+
+impl OkxInstrumentSpec {
+    /// Determine the [`OkxContract`] of this instrument, if it is a derivative.
+    pub fn contract(&self) -> Option<OkxContract> {
+        let value = self.contract_value? * self.contract_multiplier.unwrap_or(Decimal::ONE);
+        let base = self.instrument.split('-').next()?;
+
+        Some(OkxContract {
+            value,
+            inverse: !self.contract_value_currency.eq_ignore_ascii_case(base),
+        })
+    }
+}
+
+/// Deserialize an [`Okx`] numeric string as an `Option<Decimal>`, where the empty string
+/// (eg/ `ctVal` of a spot instrument) is `None`.
+fn de_okx_optional_decimal<'de, D>(deserializer: D) -> Result<Option<Decimal>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = <&str as Deserialize>::deserialize(deserializer)?;
+    if value.is_empty() {
+        Ok(None)
+    } else {
+        value.parse().map(Some).map_err(serde::de::Error::custom)
+    }
+}
+
+/// [`Okx`] [`ExchangeTransformer`] that normalises derivative contract amounts of an
+/// [`OkxMessage`] into base asset amounts using the fetched [`OkxContracts`].
+///
+/// Spot instruments are transformed in the same way as the
+/// [`StatelessTransformer`](crate::transformer::stateless::StatelessTransformer).
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct OkxContractTransformer<InstrumentKey, Kind, Input> {
+    instrument_map: Map<InstrumentKey>,
+    contracts: OkxContracts,
+    phantom: PhantomData<(Kind, Input)>,
+}
+
+impl<InstrumentKey, Kind, Input> OkxContractTransformer<InstrumentKey, Kind, Input> {
+    /// Construct a new [`Self`] using the provided [`OkxContracts`], rather than fetching them.
+    pub fn new(instrument_map: Map<InstrumentKey>, contracts: OkxContracts) -> Self {
+        Self {
+            instrument_map,
+            contracts,
+            phantom: PhantomData,
+        }
+    }
+}
+
+#[async_trait]
+impl<InstrumentKey, Kind, T> ExchangeTransformer<Okx, InstrumentKey, Kind>
+    for OkxContractTransformer<InstrumentKey, Kind, OkxMessage<T>>
+where
+    InstrumentKey: Clone + Send,
+    Kind: SubscriptionKind + Send,
+    T: OkxContractAmounts + for<'de> Deserialize<'de> + Send,
+    MarketIter<InstrumentKey, Kind::Event>: From<(ExchangeId, InstrumentKey, OkxMessage<T>)>,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        _: &[MarketEvent<InstrumentKey, Kind::Event>],
+        _: mpsc::UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        let contracts = OkxContracts::fetch(instrument_map.0.keys().cloned().collect()).await?;
+        Ok(Self::new(instrument_map, contracts))
+    }
+}
+
+impl<InstrumentKey, Kind, T> Transformer
+    for OkxContractTransformer<InstrumentKey, Kind, OkxMessage<T>>
+where
+    InstrumentKey: Clone,
+    Kind: SubscriptionKind,
+    T: OkxContractAmounts + for<'de> Deserialize<'de>,
+    MarketIter<InstrumentKey, Kind::Event>: From<(ExchangeId, InstrumentKey, OkxMessage<T>)>,
+{
+    type Error = DataError;
+    type Input = OkxMessage<T>;
+    type Output = MarketEvent<InstrumentKey, Kind::Event>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, mut input: Self::Input) -> Self::OutputIter {
+        let Some(subscription_id) = input.id() else {
+            return vec![];
+        };
+
+        // Find Instrument associated with Input
+        let instrument = match self.instrument_map.find(&subscription_id) {
+            Ok(instrument) => instrument.clone(),
+            Err(unidentifiable) => return vec![Err(DataError::from(unidentifiable))],
+        };
+
+        // Normalise derivative contract amounts into base asset amounts
+        let mut errors = Vec::new();
+        if let Some(contract) = subscription_id
+            .as_ref()
+            .split_once('|')
+            .and_then(|(_, market)| self.contracts.find(market))
+        {
+            input.data = input
+                .data
+                .into_iter()
+                .filter_map(|data| {
+                    let data = data.into_base_amounts(contract);
+                    if data.is_none() {
+                        errors.push(Err(DataError::ContractAmountInvalid(
+                            subscription_id.clone(),
+                        )));
+                    }
+                    data
+                })
+                .collect();
+        }
+
+        errors
+            .into_iter()
+            .chain(MarketIter::<InstrumentKey, Kind::Event>::from((Okx::ID, instrument, input)).0)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::okx::trade::{OkxTrade, OkxTrades},
+        subscription::trade::PublicTrades,
+    };
+    use barter_instrument::Side;
+    use chrono::{DateTime, Utc};
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn test_de_okx_instruments_response() {
+        let input = r#"
+        {
+            "code": "0",
+            "msg": "",
+            "data": [
+                {
+                    "instType": "SWAP",
+                    "instId": "BTC-USDT-SWAP",
+                    "ctVal": "0.01",
+                    "ctMult": "1",
+                    "ctValCcy": "BTC"
+                },
+                {
+                    "instType": "SWAP",
+                    "instId": "BTC-USD-SWAP",
+                    "ctVal": "100",
+                    "ctMult": "1",
+                    "ctValCcy": "USD"
+                },
+                {
+                    "instType": "SPOT",
+                    "instId": "BTC-USDT",
+                    "ctVal": "",
+                    "ctMult": "",
+                    "ctValCcy": ""
+                }
+            ]
+        }
+        "#;
+
+        let actual = serde_json::from_str::<OkxInstrumentsResponse>(input)
+            .unwrap()
+            .data
+            .iter()
+            .map(OkxInstrumentSpec::contract)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            actual,
+            vec![
+                Some(OkxContract {
+                    value: dec!(0.01),
+                    inverse: false
+                }),
+                Some(OkxContract {
+                    value: dec!(100),
+                    inverse: true
+                }),
+                None,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_okx_contract_base_amount() {
+        struct TestCase {
+            contract: OkxContract,
+            contracts: f64,
+            price: f64,
+            expected: Option<f64>,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: linear contract value is denominated in the base asset
+                contract: OkxContract {
+                    value: dec!(0.01),
+                    inverse: false,
+                },
+                contracts: 50.0,
+                price: 20000.0,
+                expected: Some(0.5),
+            },
+            TestCase {
+                // TC1: inverse contract value is denominated in the quote asset
+                contract: OkxContract {
+                    value: dec!(100),
+                    inverse: true,
+                },
+                contracts: 50.0,
+                price: 20000.0,
+                expected: Some(0.25),
+            },
+            TestCase {
+                // TC2: inverse contract at zero price cannot be converted
+                contract: OkxContract {
+                    value: dec!(100),
+                    inverse: true,
+                },
+                contracts: 50.0,
+                price: 0.0,
+                expected: None,
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = test.contract.base_amount(test.contracts, test.price);
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+
+            let actual = test.contract.base_amount_decimal(
+                Decimal::try_from(test.contracts).unwrap(),
+                Decimal::try_from(test.price).unwrap(),
+            );
+            assert_eq!(
+                actual,
+                test.expected
+                    .map(|expected| Decimal::try_from(expected).unwrap()),
+                "TC{} failed",
+                index
+            );
+        }
+    }
+
+    #[test]
+    fn test_okx_contract_transformer_trades() {
+        let mut transformer = OkxContractTransformer::<_, PublicTrades, OkxTrades>::new(
+            Map::from_iter([
+                (SubscriptionId::from("trades|BTC-USDT"), "btc_usdt"),
+                (
+                    SubscriptionId::from("trades|BTC-USDT-SWAP"),
+                    "btc_usdt_perp",
+                ),
+                (SubscriptionId::from("trades|BTC-USD-SWAP"), "btc_usd_perp"),
+            ]),
+            OkxContracts::from_iter([
+                (
+                    SmolStr::new("BTC-USDT-SWAP"),
+                    OkxContract {
+                        value: dec!(0.01),
+                        inverse: false,
+                    },
+                ),
+                (
+                    SmolStr::new("BTC-USD-SWAP"),
+                    OkxContract {
+                        value: dec!(100),
+                        inverse: true,
+                    },
+                ),
+            ]),
+        );
+
+        let trades = |subscription_id: &str, price: f64| OkxTrades {
+            subscription_id: SubscriptionId::from(subscription_id),
+            data: vec![OkxTrade {
+                id: "1".to_string(),
+                price,
+                amount: 50.0,
+                side: Side::Buy,
+                time: DateTime::<Utc>::MIN_UTC,
+            }],
+        };
+
+        let mut amounts = |subscription_id: &str, price: f64| {
+            transformer
+                .transform(trades(subscription_id, price))
+                .into_iter()
+                .map(|trade| trade.map(|trade| trade.kind.amount))
+                .collect::<Vec<_>>()
+        };
+
+        // Spot amounts are already base asset amounts
+        assert_eq!(amounts("trades|BTC-USDT", 20000.0), vec![Ok(50.0)]);
+
+        // Derivative amounts are converted from contracts into base asset amounts
+        assert_eq!(amounts("trades|BTC-USDT-SWAP", 20000.0), vec![Ok(0.5)]);
+
+        // Derivative amounts that cannot be converted are reported as errors
+        assert!(matches!(
+            amounts("trades|BTC-USD-SWAP", 0.0).as_slice(),
+            [Err(DataError::ContractAmountInvalid(_))]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_okx_contracts_fetch_spot_only() {
+        // Spot instruments have no contract specifications, so nothing is fetched
+        let actual = OkxContracts::fetch(vec![
+            SubscriptionId::from("trades|BTC-USDT"),
+            SubscriptionId::from("bbo-tbt|ETH-USDT"),
+        ])
+        .await
+        .unwrap();
+
+        assert_eq!(actual, OkxContracts::default());
+    }
+}
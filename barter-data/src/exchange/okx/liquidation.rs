@@ -0,0 +1,362 @@
+use super::{
+    Okx,
+    channel::OkxChannel,
+    contract::{OkxContract, OkxContractAmounts, OkxContracts},
+    market::OkxInstrumentType,
+};
+use crate::{
+    Identifier,
+    error::DataError,
+    event::MarketEvent,
+    exchange::{Connector, ExchangeSub},
+    subscription::{
+        Map,
+        liquidation::{Liquidation, Liquidations},
+    },
+    transformer::ExchangeTransformer,
+};
+use async_trait::async_trait;
+use barter_instrument::Side;
+use barter_integration::{Transformer, protocol::websocket::WsMessage};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use smol_str::SmolStr;
+use tokio::sync::mpsc;
+
+/// [`Okx`] real-time liquidation orders WebSocket message.
+///
+/// Liquidations are pushed for every instrument of the subscribed `instType`, and a single
+/// message may bundle several instruments, each with several liquidation `details`.
+///
+/// ### Raw Payload Examples
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+/// ```json
+/// {
+///   "arg": {
+///     "channel": "liquidation-orders",
+///     "instType": "SWAP"
+///   },
+///   "data": [
+///     {
+///       "details": [
+///         {
+///           "bkLoss": "0",
+///           "bkPx": "0.007831",
+///           "ccy": "",
+///           "posSide": "short",
+///           "side": "buy",
+///           "sz": "13",
+///           "ts": "1692266434010"
+///         }
+///       ],
+///       "instFamily": "IOST-USDT",
+///       "instId": "IOST-USDT-SWAP",
+///       "instType": "SWAP",
+///       "uly": "IOST-USDT"
+///     }
+///   ]
+/// }
+/// ```
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidations {
+    pub arg: OkxLiquidationsArg,
+    pub data: Vec<OkxLiquidationInstrument>,
+}
+
+/// [`OkxLiquidations`] message argument, echoing the `instType` scoped subscription.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidationsArg {
+    pub channel: SmolStr,
+    #[serde(rename = "instType")]
+    pub instrument_type: OkxInstrumentType,
+}
+
+/// Collection of [`OkxLiquidation`]s associated with a single [`Okx`] instrument.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidationInstrument {
+    #[serde(rename = "instId")]
+    pub instrument: SmolStr,
+    pub details: Vec<OkxLiquidation>,
+}
+
+/// [`Okx`] liquidation order details.
+///
+/// See [`OkxLiquidations`] for full raw payload examples.
+///
+/// Note that the `sz` of a derivative instrument liquidation is a number of contracts, which the
+/// [`OkxLiquidationsTransformer`] converts into a base asset quantity.
+#[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
+pub struct OkxLiquidation {
+    pub side: Side,
+    #[serde(rename = "bkPx", deserialize_with = "barter_integration::de::de_str")]
+    pub price: f64,
+    #[serde(rename = "sz", deserialize_with = "barter_integration::de::de_str")]
+    pub quantity: f64,
+    #[serde(
+        rename = "ts",
+        deserialize_with = "barter_integration::de::de_str_u64_epoch_ms_as_datetime_utc"
+    )]
+    pub time: DateTime<Utc>,
+}
+
+impl OkxContractAmounts for OkxLiquidation {
+    fn into_base_amounts(self, contract: &OkxContract) -> Option<Self> {
+        Some(Self {
+            quantity: contract.base_amount(self.quantity, self.price)?,
+            ..self
+        })
+    }
+}
+
+impl From<OkxLiquidation> for Liquidation {
+    fn from(liquidation: OkxLiquidation) -> Self {
+        Self {
+            side: liquidation.side,
+            price: liquidation.price,
+            quantity: liquidation.quantity,
+            time: liquidation.time,
+        }
+    }
+}
+
+/// [`Okx`] [`Liquidations`] [`ExchangeTransformer`].
+///
+/// Unlike the [`StatelessTransformer`](crate::transformer::stateless::StatelessTransformer), each
+/// [`OkxLiquidations`] message may contain several instruments, and liquidations for instruments
+/// that were not subscribed to are expected (the channel is scoped by `instType`), so they are
+/// ignored rather than treated as unidentifiable.
+///
+/// Derivative liquidation quantities are converted from contracts into base asset quantities
+/// using the [`OkxContracts`] fetched on initialisation.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct OkxLiquidationsTransformer<InstrumentKey> {
+    instrument_map: Map<InstrumentKey>,
+    contracts: OkxContracts,
+}
+
+impl<InstrumentKey> OkxLiquidationsTransformer<InstrumentKey> {
+    /// Construct a new [`Self`] using the provided [`OkxContracts`], rather than fetching them.
+    pub fn new(instrument_map: Map<InstrumentKey>, contracts: OkxContracts) -> Self {
+        Self {
+            instrument_map,
+            contracts,
+        }
+    }
+}
+
+#[async_trait]
+impl<InstrumentKey> ExchangeTransformer<Okx, InstrumentKey, Liquidations>
+    for OkxLiquidationsTransformer<InstrumentKey>
+where
+    InstrumentKey: Clone + Send,
+{
+    async fn init(
+        instrument_map: Map<InstrumentKey>,
+        _: &[MarketEvent<InstrumentKey, Liquidation>],
+        _: mpsc::UnboundedSender<WsMessage>,
+    ) -> Result<Self, DataError> {
+        let contracts = OkxContracts::fetch(instrument_map.0.keys().cloned().collect()).await?;
+        Ok(Self::new(instrument_map, contracts))
+    }
+}
+
+impl<InstrumentKey> Transformer for OkxLiquidationsTransformer<InstrumentKey>
+where
+    InstrumentKey: Clone,
+{
+    type Error = DataError;
+    type Input = OkxLiquidations;
+    type Output = MarketEvent<InstrumentKey, Liquidation>;
+    type OutputIter = Vec<Result<Self::Output, Self::Error>>;
+
+    fn transform(&mut self, input: Self::Input) -> Self::OutputIter {
+        let time_received = Utc::now();
+
+        input
+            .data
+            .into_iter()
+            .filter_map(|instrument| {
+                // Determine SubscriptionId associated with this instrument, ignoring any
+                // instruments of the same instType that were not subscribed to
+                let subscription_id =
+                    ExchangeSub::from((OkxChannel::LIQUIDATIONS, instrument.instrument.as_str()))
+                        .id();
+
+                let key = self.instrument_map.find(&subscription_id).ok()?;
+                let contract = self.contracts.find(&instrument.instrument).copied();
+
+                Some((subscription_id, key.clone(), contract, instrument.details))
+            })
+            .flat_map(|(subscription_id, key, contract, details)| {
+                details.into_iter().map(move |liquidation| {
+                    // Normalise derivative contract quantities into base asset quantities
+                    let liquidation = match &contract {
+                        Some(contract) => {
+                            liquidation.into_base_amounts(contract).ok_or_else(|| {
+                                DataError::ContractAmountInvalid(subscription_id.clone())
+                            })?
+                        }
+                        None => liquidation,
+                    };
+
+                    Ok(MarketEvent {
+                        time_exchange: liquidation.time,
+                        time_received,
+                        exchange: Okx::ID,
+                        instrument: key.clone(),
+                        kind: Liquidation::from(liquidation),
+                    })
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_integration::{de::datetime_utc_from_epoch_duration, subscription::SubscriptionId};
+    use rust_decimal_macros::dec;
+    use std::time::Duration;
+
+    mod de {
+        use super::*;
+
+        #[test]
+        fn test_okx_liquidations() {
+            let input = r#"
+            {
+                "arg": {
+                    "channel": "liquidation-orders",
+                    "instType": "SWAP"
+                },
+                "data": [
+                    {
+                        "details": [
+                            {
+                                "bkLoss": "0",
+                                "bkPx": "0.007831",
+                                "ccy": "",
+                                "posSide": "short",
+                                "side": "buy",
+                                "sz": "13",
+                                "ts": "1692266434010"
+                            }
+                        ],
+                        "instFamily": "IOST-USDT",
+                        "instId": "IOST-USDT-SWAP",
+                        "instType": "SWAP",
+                        "uly": "IOST-USDT"
+                    }
+                ]
+            }
+            "#;
+
+            assert_eq!(
+                serde_json::from_str::<OkxLiquidations>(input).unwrap(),
+                OkxLiquidations {
+                    arg: OkxLiquidationsArg {
+                        channel: SmolStr::new("liquidation-orders"),
+                        instrument_type: OkxInstrumentType::Swap,
+                    },
+                    data: vec![OkxLiquidationInstrument {
+                        instrument: SmolStr::new("IOST-USDT-SWAP"),
+                        details: vec![OkxLiquidation {
+                            side: Side::Buy,
+                            price: 0.007831,
+                            quantity: 13.0,
+                            time: datetime_utc_from_epoch_duration(Duration::from_millis(
+                                1692266434010
+                            )),
+                        }],
+                    }],
+                }
+            );
+        }
+    }
+
+    #[test]
+    fn test_okx_liquidations_transformer() {
+        let mut transformer = OkxLiquidationsTransformer::new(
+            Map::from_iter([
+                (
+                    SubscriptionId::from("liquidation-orders|BTC-USDT-SWAP"),
+                    "btc_usdt_perp",
+                ),
+                (
+                    SubscriptionId::from("liquidation-orders|ETH-USDT-SWAP"),
+                    "eth_usdt_perp",
+                ),
+            ]),
+            OkxContracts::from_iter([
+                (
+                    SmolStr::new("BTC-USDT-SWAP"),
+                    OkxContract {
+                        value: dec!(0.01),
+                        inverse: false,
+                    },
+                ),
+                (
+                    SmolStr::new("ETH-USDT-SWAP"),
+                    OkxContract {
+                        value: dec!(0.1),
+                        inverse: false,
+                    },
+                ),
+            ]),
+        );
+
+        let liquidation = |side, price, quantity, ms| OkxLiquidation {
+            side,
+            price,
+            quantity,
+            time: datetime_utc_from_epoch_duration(Duration::from_millis(ms)),
+        };
+
+        let input = OkxLiquidations {
+            arg: OkxLiquidationsArg {
+                channel: SmolStr::new("liquidation-orders"),
+                instrument_type: OkxInstrumentType::Swap,
+            },
+            data: vec![
+                OkxLiquidationInstrument {
+                    instrument: SmolStr::new("BTC-USDT-SWAP"),
+                    details: vec![
+                        liquidation(Side::Buy, 30000.0, 1.0, 1),
+                        liquidation(Side::Sell, 29000.0, 2.0, 2),
+                    ],
+                },
+                OkxLiquidationInstrument {
+                    instrument: SmolStr::new("SOL-USDT-SWAP"),
+                    details: vec![liquidation(Side::Sell, 20.0, 3.0, 3)],
+                },
+                OkxLiquidationInstrument {
+                    instrument: SmolStr::new("ETH-USDT-SWAP"),
+                    details: vec![liquidation(Side::Sell, 2000.0, 4.0, 4)],
+                },
+            ],
+        };
+
+        let actual = transformer
+            .transform(input)
+            .into_iter()
+            .map(|result| {
+                let event = result.unwrap();
+                (event.instrument, event.kind.side, event.kind.quantity)
+            })
+            .collect::<Vec<_>>();
+
+        // Bundled liquidations are flattened, unsubscribed SOL-USDT-SWAP is ignored, and contract
+        // quantities are converted into base asset quantities
+        assert_eq!(
+            actual,
+            vec![
+                ("btc_usdt_perp", Side::Buy, 0.01),
+                ("btc_usdt_perp", Side::Sell, 0.02),
+                ("eth_usdt_perp", Side::Sell, 0.4),
+            ]
+        );
+    }
+}
@@ -49,6 +49,53 @@ impl AsRef<str> for OkxMarket {
     }
 }
 
+impl OkxMarket {
+    /// Determine the [`OkxInstrumentType`] of this [`OkxMarket`] `instId`.
+    ///
+    /// Okx `instId` formats:
+    /// - Spot: "BTC-USDT"
+    /// - Future: "BTC-USD-230526"
+    /// - Perpetual: "BTC-USD-SWAP"
+    /// - Option: "BTC-USD-230526-35000-C"
+    ///
+    /// See docs: <https://www.okx.com/docs-v5/en/#public-data-rest-api-get-instruments>
+    pub fn instrument_type(&self) -> OkxInstrumentType {
+        let mut segments = self.0.split('-');
+        match (segments.nth(2), segments.next()) {
+            (None, _) => OkxInstrumentType::Spot,
+            (Some("SWAP"), None) => OkxInstrumentType::Swap,
+            (Some(_), None) => OkxInstrumentType::Futures,
+            (Some(_), Some(_)) => OkxInstrumentType::Option,
+        }
+    }
+}
+
+/// [`Okx`] instrument type, used to scope channels that are not subscribed per `instId`.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#public-data-websocket-liquidation-orders-channel>
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Deserialize, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum OkxInstrumentType {
+    Spot,
+    Margin,
+    Swap,
+    Futures,
+    Option,
+}
+
+impl OkxInstrumentType {
+    /// Return the &str representation of this [`OkxInstrumentType`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OkxInstrumentType::Spot => "SPOT",
+            OkxInstrumentType::Margin => "MARGIN",
+            OkxInstrumentType::Swap => "SWAP",
+            OkxInstrumentType::Futures => "FUTURES",
+            OkxInstrumentType::Option => "OPTION",
+        }
+    }
+}
+
 fn okx_market(instrument: &MarketDataInstrument) -> OkxMarket {
     let MarketDataInstrument { base, quote, kind } = instrument;
 
@@ -78,3 +125,44 @@ fn okx_market(instrument: &MarketDataInstrument) -> OkxMarket {
 fn format_expiry<'a>(expiry: DateTime<Utc>) -> DelayedFormat<StrftimeItems<'a>> {
     expiry.date_naive().format("%g%m%d")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_okx_market_instrument_type() {
+        struct TestCase {
+            input: OkxMarket,
+            expected: OkxInstrumentType,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: Spot
+                input: OkxMarket(SmolStr::new("BTC-USDT")),
+                expected: OkxInstrumentType::Spot,
+            },
+            TestCase {
+                // TC1: Perpetual
+                input: OkxMarket(SmolStr::new("BTC-USDT-SWAP")),
+                expected: OkxInstrumentType::Swap,
+            },
+            TestCase {
+                // TC2: Future
+                input: OkxMarket(SmolStr::new("BTC-USD-230526")),
+                expected: OkxInstrumentType::Futures,
+            },
+            TestCase {
+                // TC3: Option
+                input: OkxMarket(SmolStr::new("BTC-USD-231229-35000-C")),
+                expected: OkxInstrumentType::Option,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = test.input.instrument_type();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
@@ -1,13 +1,18 @@
 use self::{
-    channel::OkxChannel, market::OkxMarket, subscription::OkxSubResponse, trade::OkxTrades,
+    book::l1::OkxOrderBooksL1,
+    channel::OkxChannel,
+    contract::OkxContractTransformer,
+    liquidation::OkxLiquidationsTransformer,
+    market::OkxMarket,
+    subscription::{OkxSubArg, OkxSubResponse},
+    trade::OkxTrades,
 };
 use crate::{
     ExchangeWsStream, NoInitialSnapshots,
-    exchange::{Connector, ExchangeSub, PingInterval, StreamSelector},
+    exchange::{Connector, ExchangeSub, PingInterval, StreamSelector, SubscribeRateLimit},
     instrument::InstrumentData,
     subscriber::{WebSocketSubscriber, validator::WebSocketSubValidator},
-    subscription::trade::PublicTrades,
-    transformer::stateless::StatelessTransformer,
+    subscription::{Map, book::OrderBooksL1, liquidation::Liquidations, trade::PublicTrades},
 };
 use barter_instrument::exchange::ExchangeId;
 use barter_integration::{
@@ -16,14 +21,25 @@ use barter_integration::{
 };
 use barter_macro::{DeExchange, SerExchange};
 use derive_more::Display;
+use itertools::Itertools;
 use serde_json::json;
-use std::time::Duration;
+use std::{num::NonZeroUsize, time::Duration};
 use url::Url;
 
+/// OrderBook types for [`Okx`].
+pub mod book;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific channel used for generating [`Connector::requests`].
 pub mod channel;
 
+/// Derivative contract specification types for [`Okx`], used to normalise amounts denominated
+/// in contracts into base asset amounts.
+pub mod contract;
+
+/// Liquidation types for [`Okx`].
+pub mod liquidation;
+
 /// Defines the type that translates a Barter [`Subscription`](crate::subscription::Subscription)
 /// into an exchange [`Connector`] specific market used for generating [`Connector::requests`].
 pub mod market;
@@ -45,11 +61,33 @@ pub const BASE_URL_OKX: &str = "wss://ws.okx.com:8443/ws/v5/public";
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-connect>
 pub const PING_INTERVAL_OKX: Duration = Duration::from_secs(29);
 
+/// Maximum number of subscription arguments [`Okx`] sends in a single subscribe request, keeping
+/// each request well within the 64 KB total channel length limit.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-subscribe>
+pub const MAX_ARGS_PER_REQUEST_OKX: usize = 100;
+
+/// [`Okx`] [`SubscribeRateLimit`] of subscribe requests per connection.
+///
+/// Okx allows 3 requests per second, and 480 subscribe requests per hour. Only the per second
+/// limit is paced, since each request carries up to [`MAX_ARGS_PER_REQUEST_OKX`] arguments and
+/// a single connection is not expected to approach the hourly limit.
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#overview-websocket-connect>
+pub const SUBSCRIBE_RATE_LIMIT_OKX: SubscribeRateLimit = SubscribeRateLimit {
+    max_messages: NonZeroUsize::new(3).unwrap(),
+    interval: Duration::from_secs(1),
+};
+
 /// Convenient type alias for an Okx [`ExchangeWsStream`] using [`WebSocketSerdeParser`](barter_integration::protocol::websocket::WebSocketSerdeParser).
 pub type OkxWsStream<Transformer> = ExchangeWsStream<WebSocketSerdeParser, Transformer>;
 
 /// [`Okx`] exchange.
 ///
+/// Derivative trade, [`OrderBooksL1`] and [`Liquidations`] amounts are normalised from contracts
+/// into base asset amounts, using contract specifications fetched from the
+/// `/api/v5/public/instruments` REST endpoint when the stream is initialised.
+///
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api>
 #[derive(
     Copy,
@@ -86,14 +124,39 @@ impl Connector for Okx {
         })
     }
 
+    fn subscribe_rate_limit() -> Option<SubscribeRateLimit> {
+        Some(SUBSCRIBE_RATE_LIMIT_OKX)
+    }
+
     fn requests(exchange_subs: Vec<ExchangeSub<Self::Channel, Self::Market>>) -> Vec<WsMessage> {
-        vec![WsMessage::text(
-            json!({
-                "op": "subscribe",
-                "args": &exchange_subs,
+        // Dedup since instType scoped channels may share a single subscription argument
+        let args = exchange_subs
+            .iter()
+            .map(OkxSubArg::from)
+            .unique()
+            .collect::<Vec<_>>();
+
+        args.chunks(MAX_ARGS_PER_REQUEST_OKX)
+            .map(|args| {
+                WsMessage::text(
+                    json!({
+                        "op": "subscribe",
+                        "args": args,
+                    })
+                    .to_string(),
+                )
             })
-            .to_string(),
-        )]
+            .collect()
+    }
+
+    fn expected_responses<InstrumentKey>(map: &Map<InstrumentKey>) -> usize {
+        // One response is received per unique subscription argument
+        map.0
+            .keys()
+            .filter_map(|subscription_id| subscription_id.as_ref().split_once('|'))
+            .map(|(channel, market)| OkxSubArg::new(channel, market))
+            .unique()
+            .count()
     }
 }
 
@@ -102,5 +165,22 @@ where
     Instrument: InstrumentData,
 {
     type SnapFetcher = NoInitialSnapshots;
-    type Stream = OkxWsStream<StatelessTransformer<Self, Instrument::Key, PublicTrades, OkxTrades>>;
+    type Stream = OkxWsStream<OkxContractTransformer<Instrument::Key, PublicTrades, OkxTrades>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, OrderBooksL1> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream =
+        OkxWsStream<OkxContractTransformer<Instrument::Key, OrderBooksL1, OkxOrderBooksL1>>;
+}
+
+impl<Instrument> StreamSelector<Instrument, Liquidations> for Okx
+where
+    Instrument: InstrumentData,
+{
+    type SnapFetcher = NoInitialSnapshots;
+    type Stream = OkxWsStream<OkxLiquidationsTransformer<Instrument::Key>>;
 }
@@ -1,7 +1,10 @@
-use super::{channel::OkxChannel, market::OkxMarket};
+use super::{
+    channel::OkxChannel,
+    market::{OkxInstrumentType, OkxMarket},
+};
 use crate::exchange::subscription::ExchangeSub;
 use barter_integration::{Validator, error::SocketError};
-use serde::{Deserialize, Serialize, Serializer, ser::SerializeStruct};
+use serde::{Deserialize, Serialize, Serializer};
 
 // Implement custom Serialize to assist aesthetics of <Okx as Connector>::requests() function.
 impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
@@ -9,10 +12,66 @@ impl Serialize for ExchangeSub<OkxChannel, OkxMarket> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct("OkxSubArg", 2)?;
-        state.serialize_field("channel", self.channel.as_ref())?;
-        state.serialize_field("instId", self.market.as_ref())?;
-        state.end()
+        OkxSubArg::from(self).serialize(serializer)
+    }
+}
+
+/// [`Okx`](super::Okx) WebSocket subscription request argument.
+///
+/// Most channels are subscribed per `instId`, however some channels (eg/ liquidation-orders) are
+/// scoped by `instType`, meaning many [`ExchangeSub`]s can share a single argument.
+///
+/// ### Raw Payload Examples
+/// #### Instrument Scoped
+/// ```json
+/// {"channel": "trades", "instId": "BTC-USDT"}
+/// ```
+///
+/// #### Instrument Type Scoped
+/// ```json
+/// {"channel": "liquidation-orders", "instType": "SWAP"}
+/// ```
+///
+/// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-subscribe>
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Serialize)]
+#[serde(untagged)]
+pub enum OkxSubArg<'a> {
+    Instrument {
+        channel: &'a str,
+        #[serde(rename = "instId")]
+        instrument: &'a str,
+    },
+    InstrumentType {
+        channel: &'a str,
+        #[serde(rename = "instType")]
+        instrument_type: OkxInstrumentType,
+    },
+}
+
+impl<'a> From<&'a ExchangeSub<OkxChannel, OkxMarket>> for OkxSubArg<'a> {
+    fn from(sub: &'a ExchangeSub<OkxChannel, OkxMarket>) -> Self {
+        Self::new(sub.channel.as_ref(), sub.market.as_ref())
+    }
+}
+
+impl<'a> OkxSubArg<'a> {
+    /// Construct the [`OkxSubArg`] associated with the provided channel & market `instId`.
+    pub fn new(channel: &'a str, market: &'a str) -> Self {
+        if channel == OkxChannel::LIQUIDATIONS.as_ref() {
+            Self::InstrumentType {
+                channel,
+                instrument_type: match OkxMarket(market.into()).instrument_type() {
+                    // Spot instruments are only liquidated as margin positions
+                    OkxInstrumentType::Spot => OkxInstrumentType::Margin,
+                    instrument_type => instrument_type,
+                },
+            }
+        } else {
+            Self::Instrument {
+                channel,
+                instrument: market,
+            }
+        }
     }
 }
 
@@ -127,6 +186,40 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_okx_sub_arg_serialise() {
+        struct TestCase {
+            input: ExchangeSub<OkxChannel, OkxMarket>,
+            expected: &'static str,
+        }
+
+        let cases = vec![
+            TestCase {
+                // TC0: trades channel is scoped by instId
+                input: ExchangeSub::from((OkxChannel::TRADES, OkxMarket("BTC-USDT".into()))),
+                expected: r#"{"channel":"trades","instId":"BTC-USDT"}"#,
+            },
+            TestCase {
+                // TC1: liquidation-orders channel is scoped by instType
+                input: ExchangeSub::from((
+                    OkxChannel::LIQUIDATIONS,
+                    OkxMarket("BTC-USDT-SWAP".into()),
+                )),
+                expected: r#"{"channel":"liquidation-orders","instType":"SWAP"}"#,
+            },
+            TestCase {
+                // TC2: liquidation-orders channel for a spot instrument is scoped by MARGIN instType
+                input: ExchangeSub::from((OkxChannel::LIQUIDATIONS, OkxMarket("BTC-USDT".into()))),
+                expected: r#"{"channel":"liquidation-orders","instType":"MARGIN"}"#,
+            },
+        ];
+
+        for (index, test) in cases.into_iter().enumerate() {
+            let actual = serde_json::to_string(&test.input).unwrap();
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+
     #[test]
     fn test_validate_okx_sub_response() {
         struct TestCase {
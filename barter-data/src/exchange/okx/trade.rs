@@ -1,3 +1,4 @@
+use super::contract::{OkxContract, OkxContractAmounts};
 use crate::{
     Identifier,
     event::{MarketEvent, MarketIter},
@@ -75,6 +76,10 @@ impl<T> Identifier<Option<SubscriptionId>> for OkxMessage<T> {
 ///
 /// See [`OkxMessage`] for full raw payload examples.
 ///
+/// Note that the `sz` of a derivative instrument trade is a number of contracts, which the
+/// [`OkxContractTransformer`](super::contract::OkxContractTransformer) converts into a base asset
+/// amount.
+///
 /// See docs: <https://www.okx.com/docs-v5/en/#websocket-api-public-channel-trades-channel>
 #[derive(Clone, PartialEq, PartialOrd, Debug, Deserialize, Serialize)]
 pub struct OkxTrade {
@@ -117,6 +122,15 @@ impl<InstrumentKey: Clone> From<(ExchangeId, InstrumentKey, OkxTrades)>
     }
 }
 
+impl OkxContractAmounts for OkxTrade {
+    fn into_base_amounts(self, contract: &OkxContract) -> Option<Self> {
+        Some(Self {
+            amount: contract.base_amount(self.amount, self.price)?,
+            ..self
+        })
+    }
+}
+
 /// Deserialize an [`OkxMessage`] "arg" field as a Barter [`SubscriptionId`].
 fn de_okx_message_arg_as_subscription_id<'de, D>(
     deserializer: D,
@@ -237,6 +237,7 @@ where
             websocket,
             map: instrument_map,
             buffered_websocket_events,
+            ping_interval: server_ping_interval,
         } = Exchange::Subscriber::subscribe(subscriptions).await?;
 
         // Fetch any required initial MarketEvent snapshots
@@ -254,7 +255,11 @@ where
         ));
 
         // Spawn optional task to distribute custom application-level pings to the exchange
-        if let Some(ping_interval) = Exchange::ping_interval() {
+        if let Some(mut ping_interval) = Exchange::ping_interval() {
+            if let Some(interval) = server_ping_interval {
+                ping_interval.interval = tokio::time::interval(interval);
+            }
+
             tokio::spawn(schedule_pings_to_exchange(
                 Exchange::ID,
                 ws_sink_tx.clone(),
@@ -41,9 +41,9 @@ pub async fn init_indexed_multi_exchange_market_stream(
     // Generate indexed market data Subscriptions
     let subscriptions = generate_indexed_market_data_subscription_batches(instruments, sub_kinds);
 
-    // Initialise an indexed MarketStream via DynamicStreams
-    let stream = DynamicStreams::init(subscriptions)
-        .await?
+    // Initialise an indexed MarketStream via DynamicStreams, supporting every exchange
+    let (streams, _handle) = DynamicStreams::init_with_handle(subscriptions).await?;
+    let stream = streams
         .select_all::<MarketStreamResult<InstrumentIndex, DataKind>>()
         .with_error_handler(|error| warn!(?error, "MarketStream generated error"));
 
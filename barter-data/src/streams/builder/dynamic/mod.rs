@@ -2,6 +2,7 @@ use crate::{
     Identifier,
     error::DataError,
     exchange::{
+        StreamSelector,
         binance::{futures::BinanceFuturesUsd, market::BinanceMarket, spot::BinanceSpot},
         bitfinex::{Bitfinex, market::BitfinexMarket},
         bitmex::{Bitmex, market::BitmexMarket},
@@ -14,7 +15,9 @@ use crate::{
             perpetual::{GateioPerpetualsBtc, GateioPerpetualsUsd},
             spot::GateioSpot,
         },
+        hyperliquid::{Hyperliquid, market::HyperliquidMarket},
         kraken::{Kraken, market::KrakenMarket},
+        kucoin::{Kucoin, market::KucoinMarket},
         okx::{Okx, market::OkxMarket},
     },
     instrument::InstrumentData,
@@ -23,7 +26,7 @@ use crate::{
         reconnect::stream::ReconnectingStream,
     },
     subscription::{
-        SubKind, Subscription,
+        SubKind, Subscription, SubscriptionKind,
         book::{OrderBookEvent, OrderBookL1, OrderBooksL1, OrderBooksL2},
         liquidation::{Liquidation, Liquidations},
         trade::{PublicTrade, PublicTrades},
@@ -35,14 +38,18 @@ use barter_integration::{
     channel::{UnboundedRx, UnboundedTx, mpsc_unbounded},
     error::SocketError,
 };
+use derive_more::From;
 use fnv::FnvHashMap;
-use futures::{Stream, stream::SelectAll};
-use futures_util::{StreamExt, future::try_join_all};
+use futures::{Stream, future::BoxFuture, stream::SelectAll};
+use futures_util::{StreamExt, future::join_all};
 use itertools::Itertools;
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
 use std::{
     fmt::{Debug, Display},
     sync::Arc,
 };
+use tokio::task::{AbortHandle, JoinHandle};
 use tokio_stream::wrappers::UnboundedReceiverStream;
 use vecmap::VecMap;
 
@@ -69,6 +76,11 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
     /// WebSocket `Stream` under the hood. If the batch contains more-than-one [`ExchangeId`] and/or
     /// [`SubKind`], it will be further split under the hood for compile-time reasons.
     ///
+    /// Note that `Hyperliquid`, `Kucoin`, `Gateio` & `Okx` [`OrderBooksL1`], and `Okx`
+    /// [`Liquidations`] [`Subscription`]s are only supported by [`Self::init_with_handle`], since
+    /// they require additional [`Identifier`] bounds. Here, they return a
+    /// [`DataError::Unsupported`].
+    ///
     /// ## Examples
     /// Please see barter-data-rs/examples/dynamic_multi_stream_multi_exchange.rs for a
     /// comprehensive example of how to use this market data stream initialiser.
@@ -106,561 +118,138 @@ impl<InstrumentKey> DynamicStreams<InstrumentKey> {
         Subscription<Kraken, Instrument, PublicTrades>: Identifier<KrakenMarket>,
         Subscription<Kraken, Instrument, OrderBooksL1>: Identifier<KrakenMarket>,
         Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+    {
+        Self::init_with_stream_fn(subscription_batches, init_stream::<Instrument>)
+            .await
+            .map(|(streams, _handle)| streams)
+    }
+
+    /// Initialise a set of `Streams` by providing one or more [`Subscription`] batches, returning
+    /// a [`DynamicStreamsHandle`] that can be used to add or remove [`Subscription`] batches at
+    /// runtime.
+    ///
+    /// The provided batches are assigned a [`DynamicBatchId`] in the order they are provided,
+    /// starting from `DynamicBatchId(0)`.
+    ///
+    /// Unlike [`Self::init`], every [`ExchangeId`] & [`SubKind`] combination supported by
+    /// [`exchange_supports_instrument_kind_sub_kind`](crate::subscription::exchange_supports_instrument_kind_sub_kind)
+    /// can be initialised.
+    ///
+    /// See [`Self::init`] for how each [`Subscription`] batch is initialised.
+    pub async fn init_with_handle<SubBatchIter, SubIter, Sub, Instrument>(
+        subscription_batches: SubBatchIter,
+    ) -> Result<(Self, DynamicStreamsHandle<Instrument>), DataError>
+    where
+        SubBatchIter: IntoIterator<Item = SubIter>,
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+        Instrument: InstrumentData<Key = InstrumentKey> + Ord + Display + 'static,
+        InstrumentKey: Debug + Clone + Send + 'static,
+        Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+        Subscription<BinanceSpot, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+        Subscription<BinanceSpot, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
+        Subscription<BinanceFuturesUsd, Instrument, Liquidations>: Identifier<BinanceMarket>,
+        Subscription<Bitfinex, Instrument, PublicTrades>: Identifier<BitfinexMarket>,
+        Subscription<Bitmex, Instrument, PublicTrades>: Identifier<BitmexMarket>,
+        Subscription<BybitSpot, Instrument, PublicTrades>: Identifier<BybitMarket>,
+        Subscription<BybitSpot, Instrument, OrderBooksL1>: Identifier<BybitMarket>,
+        Subscription<BybitSpot, Instrument, OrderBooksL2>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, PublicTrades>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, OrderBooksL1>: Identifier<BybitMarket>,
+        Subscription<BybitPerpetualsUsd, Instrument, OrderBooksL2>: Identifier<BybitMarket>,
+        Subscription<Coinbase, Instrument, PublicTrades>: Identifier<CoinbaseMarket>,
+        Subscription<GateioSpot, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioSpot, Instrument, OrderBooksL1>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioFuturesBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsUsd, Instrument, OrderBooksL1>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<GateioPerpetualsBtc, Instrument, OrderBooksL1>: Identifier<GateioMarket>,
+        Subscription<GateioOptions, Instrument, PublicTrades>: Identifier<GateioMarket>,
+        Subscription<Hyperliquid, Instrument, PublicTrades>: Identifier<HyperliquidMarket>,
+        Subscription<Hyperliquid, Instrument, OrderBooksL1>: Identifier<HyperliquidMarket>,
+        Subscription<Hyperliquid, Instrument, OrderBooksL2>: Identifier<HyperliquidMarket>,
+        Subscription<Kraken, Instrument, PublicTrades>: Identifier<KrakenMarket>,
+        Subscription<Kraken, Instrument, OrderBooksL1>: Identifier<KrakenMarket>,
+        Subscription<Kucoin, Instrument, PublicTrades>: Identifier<KucoinMarket>,
+        Subscription<Kucoin, Instrument, OrderBooksL1>: Identifier<KucoinMarket>,
+        Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+        Subscription<Okx, Instrument, OrderBooksL1>: Identifier<OkxMarket>,
+        Subscription<Okx, Instrument, Liquidations>: Identifier<OkxMarket>,
+    {
+        Self::init_with_stream_fn(subscription_batches, init_stream_with_handle::<Instrument>).await
+    }
+
+    /// Initialise a set of `Streams` from the provided [`Subscription`] batches, using the
+    /// provided [`InitStreamFn`] to initialise each `Stream`.
+    async fn init_with_stream_fn<SubBatchIter, SubIter, Sub, Instrument>(
+        subscription_batches: SubBatchIter,
+        init_stream: InitStreamFn<Instrument>,
+    ) -> Result<(Self, DynamicStreamsHandle<Instrument>), DataError>
+    where
+        SubBatchIter: IntoIterator<Item = SubIter>,
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+        Instrument: InstrumentData<Key = InstrumentKey> + Ord + Display + 'static,
+        InstrumentKey: Debug + Clone + Send + 'static,
     {
         // Validate & dedup Subscription batches
         let batches = validate_batches(subscription_batches)?;
 
         // Generate required Channels from Subscription batches
-        let channels = Channels::try_from(&batches)?;
-
-        let futures =
-            batches.into_iter().map(|mut batch| {
-                batch.sort_unstable_by_key(|sub| (sub.exchange, sub.kind));
-                let by_exchange_by_sub_kind =
-                    batch.into_iter().chunk_by(|sub| (sub.exchange, sub.kind));
-
-                let batch_futures =
-                    by_exchange_by_sub_kind
-                        .into_iter()
-                        .map(|((exchange, sub_kind), subs)| {
-                            let subs = subs.into_iter().collect::<Vec<_>>();
-                            let txs = Arc::clone(&channels.txs);
-                            async move {
-                                match (exchange, sub_kind) {
-                                    (ExchangeId::BinanceSpot, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BinanceSpot::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BinanceSpot, SubKind::OrderBooksL1) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BinanceSpot::default(),
-                                                        sub.instrument,
-                                                        OrderBooksL1,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.l1s.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BinanceSpot, SubKind::OrderBooksL2) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BinanceSpot::default(),
-                                                        sub.instrument,
-                                                        OrderBooksL2,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.l2s.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BinanceFuturesUsd::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL1) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::<_, Instrument, _>::new(
-                                                        BinanceFuturesUsd::default(),
-                                                        sub.instrument,
-                                                        OrderBooksL1,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.l1s.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL2) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::<_, Instrument, _>::new(
-                                                        BinanceFuturesUsd::default(),
-                                                        sub.instrument,
-                                                        OrderBooksL2,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.l2s.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::<_, Instrument, _>::new(
-                                                        BinanceFuturesUsd::default(),
-                                                        sub.instrument,
-                                                        Liquidations,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.liquidations.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        Bitfinex,
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::Bitmex, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        Bitmex,
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BybitSpot::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BybitSpot, SubKind::OrderBooksL1) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BybitSpot::default(),
-                                                        sub.instrument,
-                                                        OrderBooksL1,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.l1s.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BybitSpot, SubKind::OrderBooksL2) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BybitSpot::default(),
-                                                        sub.instrument,
-                                                        OrderBooksL2,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.l2s.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BybitPerpetualsUsd::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BybitPerpetualsUsd, SubKind::OrderBooksL1) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BybitSpot::default(),
-                                                        sub.instrument,
-                                                        OrderBooksL1,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.l1s.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::BybitPerpetualsUsd, SubKind::OrderBooksL2) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        BybitSpot::default(),
-                                                        sub.instrument,
-                                                        OrderBooksL2,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.l2s.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::Coinbase, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        Coinbase,
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        GateioSpot::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::GateioFuturesUsd, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        GateioFuturesUsd::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::GateioFuturesBtc, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        GateioFuturesBtc::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::GateioPerpetualsUsd, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        GateioPerpetualsUsd::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        GateioPerpetualsBtc::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        GateioOptions::default(),
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::Kraken, SubKind::PublicTrades) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        Kraken,
-                                                        sub.instrument,
-                                                        PublicTrades,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::Kraken, SubKind::OrderBooksL1) => {
-                                        init_market_stream(
-                                            STREAM_RECONNECTION_POLICY,
-                                            subs.into_iter()
-                                                .map(|sub| {
-                                                    Subscription::new(
-                                                        Kraken,
-                                                        sub.instrument,
-                                                        OrderBooksL1,
-                                                    )
-                                                })
-                                                .collect(),
-                                        )
-                                        .await
-                                        .map(|stream| {
-                                            tokio::spawn(stream.forward_to(
-                                                txs.l1s.get(&exchange).unwrap().clone(),
-                                            ))
-                                        })
-                                    }
-                                    (ExchangeId::Okx, SubKind::PublicTrades) => init_market_stream(
-                                        STREAM_RECONNECTION_POLICY,
-                                        subs.into_iter()
-                                            .map(|sub| {
-                                                Subscription::new(Okx, sub.instrument, PublicTrades)
-                                            })
-                                            .collect(),
-                                    )
-                                    .await
-                                    .map(|stream| {
-                                        tokio::spawn(
-                                            stream.forward_to(
-                                                txs.trades.get(&exchange).unwrap().clone(),
-                                            ),
-                                        )
-                                    }),
-                                    (exchange, sub_kind) => {
-                                        Err(DataError::Unsupported { exchange, sub_kind })
-                                    }
-                                }
-                            }
-                        });
-
-                try_join_all(batch_futures)
-            });
+        let Channels { txs, rxs } = Channels::try_from(&batches)?;
 
-        try_join_all(futures).await?;
+        let handle = DynamicStreamsHandle::new(txs, init_stream);
 
-        Ok(Self {
-            trades: channels
-                .rxs
+        // Initialise the initial Subscription batches, assigning their DynamicBatchIds in order
+        let futures = batches
+            .into_iter()
+            .map(|batch| init_batch(handle.init_stream, batch, &handle.txs));
+
+        let results = join_all(futures).await;
+
+        // If any batch fails to initialise, stop every Stream of the batches that succeeded
+        if let Some(error) = results.iter().find_map(|result| result.as_ref().err()) {
+            let error = error.clone();
+            results
+                .into_iter()
+                .flatten()
+                .flatten()
+                .for_each(|abort_handle| abort_handle.abort());
+            return Err(error);
+        }
+
+        for abort_handles in results.into_iter().flatten() {
+            handle.batches.lock().insert(abort_handles);
+        }
+        let streams = Self {
+            trades: rxs
                 .trades
                 .into_iter()
                 .map(|(exchange, rx)| (exchange, rx.into_stream()))
                 .collect(),
-            l1s: channels
-                .rxs
+            l1s: rxs
                 .l1s
                 .into_iter()
                 .map(|(exchange, rx)| (exchange, rx.into_stream()))
                 .collect(),
-            l2s: channels
-                .rxs
+            l2s: rxs
                 .l2s
                 .into_iter()
                 .map(|(exchange, rx)| (exchange, rx.into_stream()))
                 .collect(),
-            liquidations: channels
-                .rxs
+            liquidations: rxs
                 .liquidations
                 .into_iter()
                 .map(|(exchange, rx)| (exchange, rx.into_stream()))
                 .collect(),
-        })
+        };
+
+        Ok((streams, handle))
     }
 
     /// Remove an exchange [`PublicTrade`] `Stream` from the [`DynamicStreams`] collection.
@@ -816,6 +405,841 @@ where
     Ok(batch)
 }
 
+/// Unique identifier of a [`Subscription`] batch initialised by [`DynamicStreams`], used to
+/// [`unsubscribe`](DynamicStreamsHandle::unsubscribe) the batch at runtime.
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Default, Deserialize, Serialize, From,
+)]
+pub struct DynamicBatchId(pub u64);
+
+/// Initialises a `Stream` for a collection of [`Subscription`]s that share the same
+/// [`ExchangeId`] & [`SubKind`], spawning a task to forward it's events to the associated
+/// [`DynamicStreams`] channel.
+type InitStreamFn<Instrument> = fn(
+    ExchangeId,
+    SubKind,
+    Vec<Subscription<ExchangeId, Instrument, SubKind>>,
+    Arc<Txs<<Instrument as InstrumentData>::Key>>,
+) -> BoxFuture<'static, Result<JoinHandle<()>, DataError>>;
+
+/// [`InitStreamFn`] used by [`DynamicStreams::init`], supporting the [`ExchangeId`] &
+/// [`SubKind`] combinations bounded by [`DynamicStreams::init`].
+fn init_stream<Instrument>(
+    exchange: ExchangeId,
+    sub_kind: SubKind,
+    subs: Vec<Subscription<ExchangeId, Instrument, SubKind>>,
+    txs: Arc<Txs<Instrument::Key>>,
+) -> BoxFuture<'static, Result<JoinHandle<()>, DataError>>
+where
+    Instrument: InstrumentData + Display + 'static,
+    Instrument::Key: Debug + Clone + Send + 'static,
+    Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+    Subscription<BinanceSpot, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+    Subscription<BinanceSpot, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
+    Subscription<BinanceFuturesUsd, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+    Subscription<BinanceFuturesUsd, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+    Subscription<BinanceFuturesUsd, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
+    Subscription<BinanceFuturesUsd, Instrument, Liquidations>: Identifier<BinanceMarket>,
+    Subscription<Bitfinex, Instrument, PublicTrades>: Identifier<BitfinexMarket>,
+    Subscription<Bitmex, Instrument, PublicTrades>: Identifier<BitmexMarket>,
+    Subscription<BybitSpot, Instrument, PublicTrades>: Identifier<BybitMarket>,
+    Subscription<BybitSpot, Instrument, OrderBooksL1>: Identifier<BybitMarket>,
+    Subscription<BybitSpot, Instrument, OrderBooksL2>: Identifier<BybitMarket>,
+    Subscription<BybitPerpetualsUsd, Instrument, PublicTrades>: Identifier<BybitMarket>,
+    Subscription<BybitPerpetualsUsd, Instrument, OrderBooksL1>: Identifier<BybitMarket>,
+    Subscription<BybitPerpetualsUsd, Instrument, OrderBooksL2>: Identifier<BybitMarket>,
+    Subscription<Coinbase, Instrument, PublicTrades>: Identifier<CoinbaseMarket>,
+    Subscription<GateioSpot, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioFuturesUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioFuturesBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioPerpetualsUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioPerpetualsBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioOptions, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<Kraken, Instrument, PublicTrades>: Identifier<KrakenMarket>,
+    Subscription<Kraken, Instrument, OrderBooksL1>: Identifier<KrakenMarket>,
+    Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+{
+    Box::pin(async move {
+        match (exchange, sub_kind) {
+            (ExchangeId::BinanceSpot, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    BinanceSpot::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BinanceSpot, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    BinanceSpot::default(),
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BinanceSpot, SubKind::OrderBooksL2) => {
+                init_forwarded_stream(
+                    BinanceSpot::default(),
+                    OrderBooksL2,
+                    subs,
+                    txs.l2s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    BinanceFuturesUsd::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    BinanceFuturesUsd::default(),
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL2) => {
+                init_forwarded_stream(
+                    BinanceFuturesUsd::default(),
+                    OrderBooksL2,
+                    subs,
+                    txs.l2s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
+                init_forwarded_stream(
+                    BinanceFuturesUsd::default(),
+                    Liquidations,
+                    subs,
+                    txs.liquidations.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    Bitfinex,
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Bitmex, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    Bitmex,
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    BybitSpot::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BybitSpot, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    BybitSpot::default(),
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BybitSpot, SubKind::OrderBooksL2) => {
+                init_forwarded_stream(
+                    BybitSpot::default(),
+                    OrderBooksL2,
+                    subs,
+                    txs.l2s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    BybitPerpetualsUsd::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BybitPerpetualsUsd, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    BybitSpot::default(),
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BybitPerpetualsUsd, SubKind::OrderBooksL2) => {
+                init_forwarded_stream(
+                    BybitSpot::default(),
+                    OrderBooksL2,
+                    subs,
+                    txs.l2s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Coinbase, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    Coinbase,
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    GateioSpot::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioFuturesUsd, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    GateioFuturesUsd::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioFuturesBtc, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    GateioFuturesBtc::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioPerpetualsUsd, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    GateioPerpetualsUsd::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    GateioPerpetualsBtc::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    GateioOptions::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Kraken, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    Kraken,
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Kraken, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    Kraken,
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Okx, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    Okx,
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (exchange, sub_kind) => Err(DataError::Unsupported { exchange, sub_kind }),
+        }
+    })
+}
+
+/// [`InitStreamFn`] used by [`DynamicStreams::init_with_handle`], supporting every
+/// [`ExchangeId`] & [`SubKind`] combination.
+fn init_stream_with_handle<Instrument>(
+    exchange: ExchangeId,
+    sub_kind: SubKind,
+    subs: Vec<Subscription<ExchangeId, Instrument, SubKind>>,
+    txs: Arc<Txs<Instrument::Key>>,
+) -> BoxFuture<'static, Result<JoinHandle<()>, DataError>>
+where
+    Instrument: InstrumentData + Display + 'static,
+    Instrument::Key: Debug + Clone + Send + 'static,
+    Subscription<BinanceSpot, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+    Subscription<BinanceSpot, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+    Subscription<BinanceSpot, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
+    Subscription<BinanceFuturesUsd, Instrument, PublicTrades>: Identifier<BinanceMarket>,
+    Subscription<BinanceFuturesUsd, Instrument, OrderBooksL1>: Identifier<BinanceMarket>,
+    Subscription<BinanceFuturesUsd, Instrument, OrderBooksL2>: Identifier<BinanceMarket>,
+    Subscription<BinanceFuturesUsd, Instrument, Liquidations>: Identifier<BinanceMarket>,
+    Subscription<Bitfinex, Instrument, PublicTrades>: Identifier<BitfinexMarket>,
+    Subscription<Bitmex, Instrument, PublicTrades>: Identifier<BitmexMarket>,
+    Subscription<BybitSpot, Instrument, PublicTrades>: Identifier<BybitMarket>,
+    Subscription<BybitSpot, Instrument, OrderBooksL1>: Identifier<BybitMarket>,
+    Subscription<BybitSpot, Instrument, OrderBooksL2>: Identifier<BybitMarket>,
+    Subscription<BybitPerpetualsUsd, Instrument, PublicTrades>: Identifier<BybitMarket>,
+    Subscription<BybitPerpetualsUsd, Instrument, OrderBooksL1>: Identifier<BybitMarket>,
+    Subscription<BybitPerpetualsUsd, Instrument, OrderBooksL2>: Identifier<BybitMarket>,
+    Subscription<Coinbase, Instrument, PublicTrades>: Identifier<CoinbaseMarket>,
+    Subscription<GateioSpot, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioSpot, Instrument, OrderBooksL1>: Identifier<GateioMarket>,
+    Subscription<GateioFuturesUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioFuturesBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioPerpetualsUsd, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioPerpetualsUsd, Instrument, OrderBooksL1>: Identifier<GateioMarket>,
+    Subscription<GateioPerpetualsBtc, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<GateioPerpetualsBtc, Instrument, OrderBooksL1>: Identifier<GateioMarket>,
+    Subscription<GateioOptions, Instrument, PublicTrades>: Identifier<GateioMarket>,
+    Subscription<Hyperliquid, Instrument, PublicTrades>: Identifier<HyperliquidMarket>,
+    Subscription<Hyperliquid, Instrument, OrderBooksL1>: Identifier<HyperliquidMarket>,
+    Subscription<Hyperliquid, Instrument, OrderBooksL2>: Identifier<HyperliquidMarket>,
+    Subscription<Kraken, Instrument, PublicTrades>: Identifier<KrakenMarket>,
+    Subscription<Kraken, Instrument, OrderBooksL1>: Identifier<KrakenMarket>,
+    Subscription<Kucoin, Instrument, PublicTrades>: Identifier<KucoinMarket>,
+    Subscription<Kucoin, Instrument, OrderBooksL1>: Identifier<KucoinMarket>,
+    Subscription<Okx, Instrument, PublicTrades>: Identifier<OkxMarket>,
+    Subscription<Okx, Instrument, OrderBooksL1>: Identifier<OkxMarket>,
+    Subscription<Okx, Instrument, Liquidations>: Identifier<OkxMarket>,
+{
+    Box::pin(async move {
+        match (exchange, sub_kind) {
+            (ExchangeId::BinanceSpot, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    BinanceSpot::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BinanceSpot, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    BinanceSpot::default(),
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BinanceSpot, SubKind::OrderBooksL2) => {
+                init_forwarded_stream(
+                    BinanceSpot::default(),
+                    OrderBooksL2,
+                    subs,
+                    txs.l2s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BinanceFuturesUsd, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    BinanceFuturesUsd::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    BinanceFuturesUsd::default(),
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BinanceFuturesUsd, SubKind::OrderBooksL2) => {
+                init_forwarded_stream(
+                    BinanceFuturesUsd::default(),
+                    OrderBooksL2,
+                    subs,
+                    txs.l2s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BinanceFuturesUsd, SubKind::Liquidations) => {
+                init_forwarded_stream(
+                    BinanceFuturesUsd::default(),
+                    Liquidations,
+                    subs,
+                    txs.liquidations.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Bitfinex, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    Bitfinex,
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Bitmex, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    Bitmex,
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BybitSpot, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    BybitSpot::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BybitSpot, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    BybitSpot::default(),
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BybitSpot, SubKind::OrderBooksL2) => {
+                init_forwarded_stream(
+                    BybitSpot::default(),
+                    OrderBooksL2,
+                    subs,
+                    txs.l2s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BybitPerpetualsUsd, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    BybitPerpetualsUsd::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BybitPerpetualsUsd, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    BybitSpot::default(),
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::BybitPerpetualsUsd, SubKind::OrderBooksL2) => {
+                init_forwarded_stream(
+                    BybitSpot::default(),
+                    OrderBooksL2,
+                    subs,
+                    txs.l2s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Coinbase, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    Coinbase,
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioSpot, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    GateioSpot::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioSpot, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    GateioSpot::default(),
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioFuturesUsd, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    GateioFuturesUsd::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioFuturesBtc, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    GateioFuturesBtc::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioPerpetualsUsd, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    GateioPerpetualsUsd::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioPerpetualsUsd, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    GateioPerpetualsUsd::default(),
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioPerpetualsBtc, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    GateioPerpetualsBtc::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioPerpetualsBtc, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    GateioPerpetualsBtc::default(),
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::GateioOptions, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    GateioOptions::default(),
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Hyperliquid, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    Hyperliquid,
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Hyperliquid, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    Hyperliquid,
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Hyperliquid, SubKind::OrderBooksL2) => {
+                init_forwarded_stream(
+                    Hyperliquid,
+                    OrderBooksL2,
+                    subs,
+                    txs.l2s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Kraken, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    Kraken,
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Kraken, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    Kraken,
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Kucoin, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    Kucoin,
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Kucoin, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    Kucoin,
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Okx, SubKind::PublicTrades) => {
+                init_forwarded_stream(
+                    Okx,
+                    PublicTrades,
+                    subs,
+                    txs.trades.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Okx, SubKind::OrderBooksL1) => {
+                init_forwarded_stream(
+                    Okx,
+                    OrderBooksL1,
+                    subs,
+                    txs.l1s.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (ExchangeId::Okx, SubKind::Liquidations) => {
+                init_forwarded_stream(
+                    Okx,
+                    Liquidations,
+                    subs,
+                    txs.liquidations.get(&exchange).unwrap().clone(),
+                )
+                .await
+            }
+            (exchange, sub_kind) => Err(DataError::Unsupported { exchange, sub_kind }),
+        }
+    })
+}
+
+/// Initialise a `Stream` for a collection of [`Subscription`]s that share the same
+/// [`ExchangeId`] & [`SubKind`], spawning a task to forward it's events to the provided
+/// [`DynamicStreams`] channel.
+async fn init_forwarded_stream<Exchange, Instrument, Kind>(
+    exchange: Exchange,
+    kind: Kind,
+    subs: Vec<Subscription<ExchangeId, Instrument, SubKind>>,
+    tx: UnboundedTx<MarketStreamResult<Instrument::Key, Kind::Event>>,
+) -> Result<JoinHandle<()>, DataError>
+where
+    Exchange: StreamSelector<Instrument, Kind> + Clone + Send + Sync + 'static,
+    Instrument: InstrumentData + Display + 'static,
+    Instrument::Key: Send + 'static,
+    Kind: SubscriptionKind + Display + Clone + Send + Sync + 'static,
+    Kind::Event: Clone + Send,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let subs = subs
+        .into_iter()
+        .map(|sub| Subscription::new(exchange.clone(), sub.instrument, kind.clone()))
+        .collect();
+
+    init_market_stream(STREAM_RECONNECTION_POLICY, subs)
+        .await
+        .map(|stream| tokio::spawn(stream.forward_to(tx)))
+}
+
+/// Handle to a running [`DynamicStreams`] that can be used to add or remove [`Subscription`]
+/// batches at runtime.
+///
+/// Each batch added via [`Self::subscribe`] initialises at-least-one new WebSocket `Stream`,
+/// forwarding events to the existing [`DynamicStreams`] exchange `Streams`. Therefore, every
+/// [`ExchangeId`] & [`SubKind`] combination subscribed to at runtime must have been included in
+/// the [`Subscription`] batches provided to [`DynamicStreams::init_with_handle`].
+///
+/// Existing WebSocket connections are never re-used for runtime [`Subscription`]s, even if the
+/// exchange supports subscribing over an established connection. This is because a
+/// `MarketStream` fixes its instrument map & transformer state (eg/ OrderBook sequencers) when it
+/// connects. Every runtime batch therefore counts towards the exchange connection limits.
+///
+/// Note that:
+/// - Dropping every [`DynamicStreamsHandle`] does not stop any running `Streams`.
+/// - The [`DynamicStreams`] exchange `Streams` never end while a [`DynamicStreamsHandle`] exists,
+///   since it holds the channel senders required to forward events of future [`Subscription`]
+///   batches.
+#[derive(Debug, Clone)]
+pub struct DynamicStreamsHandle<Instrument>
+where
+    Instrument: InstrumentData,
+{
+    txs: Arc<Txs<Instrument::Key>>,
+    batches: Arc<Mutex<Batches>>,
+    init_stream: InitStreamFn<Instrument>,
+}
+
+impl<Instrument> DynamicStreamsHandle<Instrument>
+where
+    Instrument: InstrumentData,
+{
+    fn new(txs: Arc<Txs<Instrument::Key>>, init_stream: InitStreamFn<Instrument>) -> Self {
+        Self {
+            txs,
+            batches: Arc::default(),
+            init_stream,
+        }
+    }
+
+    /// Initialise a new [`Subscription`] batch, forwarding events to the existing
+    /// [`DynamicStreams`] exchange `Streams`.
+    ///
+    /// Returns the [`DynamicBatchId`] that can be used to [`Self::unsubscribe`] the batch.
+    pub async fn subscribe<SubIter, Sub>(&self, batch: SubIter) -> Result<DynamicBatchId, DataError>
+    where
+        SubIter: IntoIterator<Item = Sub>,
+        Sub: Into<Subscription<ExchangeId, Instrument, SubKind>>,
+        Instrument: Ord,
+    {
+        // Validate & dedup Subscription batch
+        let batch = validate_subscriptions(batch)?;
+        if batch.is_empty() {
+            return Err(DataError::SubscriptionsEmpty);
+        }
+
+        // Ensure a DynamicStreams exchange Stream exists to forward every Subscription events to
+        if let Some(sub) = batch
+            .iter()
+            .find(|sub| !self.txs.contains(sub.exchange, sub.kind))
+        {
+            return Err(DataError::DynamicStreamMissing {
+                exchange: sub.exchange,
+                sub_kind: sub.kind,
+            });
+        }
+
+        let abort_handles = init_batch(self.init_stream, batch, &self.txs).await?;
+
+        Ok(self.batches.lock().insert(abort_handles))
+    }
+
+    /// Stop every `Stream` initialised for the [`Subscription`] batch associated with the
+    /// provided [`DynamicBatchId`].
+    ///
+    /// Returns `false` if no active batch is associated with the [`DynamicBatchId`].
+    pub fn unsubscribe(&self, batch: DynamicBatchId) -> bool {
+        self.batches
+            .lock()
+            .active
+            .remove(&batch)
+            .map(|abort_handles| abort_handles.iter().for_each(AbortHandle::abort))
+            .is_some()
+    }
+
+    /// Returns the [`DynamicBatchId`] of every active [`Subscription`] batch, in ascending order.
+    pub fn batches(&self) -> Vec<DynamicBatchId> {
+        self.batches
+            .lock()
+            .active
+            .keys()
+            .copied()
+            .sorted()
+            .collect()
+    }
+}
+
+/// Initialise every `Stream` required for a validated [`Subscription`] batch.
+///
+/// If any `Stream` fails to initialise, every `Stream` already initialised for the batch is
+/// stopped before returning the error.
+async fn init_batch<Instrument>(
+    init_stream: InitStreamFn<Instrument>,
+    mut batch: Vec<Subscription<ExchangeId, Instrument, SubKind>>,
+    txs: &Arc<Txs<Instrument::Key>>,
+) -> Result<Vec<AbortHandle>, DataError>
+where
+    Instrument: InstrumentData,
+{
+    batch.sort_unstable_by_key(|sub| (sub.exchange, sub.kind));
+    let by_exchange_by_sub_kind = batch.into_iter().chunk_by(|sub| (sub.exchange, sub.kind));
+
+    let futures = by_exchange_by_sub_kind
+        .into_iter()
+        .map(|((exchange, sub_kind), subs)| {
+            init_stream(exchange, sub_kind, subs.collect(), Arc::clone(txs))
+        })
+        .collect::<Vec<_>>();
+
+    let results = join_all(futures).await;
+
+    if let Some(error) = results.iter().find_map(|result| result.as_ref().err()) {
+        let error = error.clone();
+        results
+            .into_iter()
+            .flatten()
+            .for_each(|handle| handle.abort());
+        return Err(error);
+    }
+
+    Ok(results
+        .into_iter()
+        .flatten()
+        .map(|handle| handle.abort_handle())
+        .collect())
+}
+
+#[derive(Debug, Default)]
+struct Batches {
+    next_id: DynamicBatchId,
+    active: FnvHashMap<DynamicBatchId, Vec<AbortHandle>>,
+}
+
+impl Batches {
+    fn insert(&mut self, abort_handles: Vec<AbortHandle>) -> DynamicBatchId {
+        let id = self.next_id;
+        self.next_id = DynamicBatchId(id.0 + 1);
+        self.active.insert(id, abort_handles);
+        id
+    }
+}
+
 struct Channels<InstrumentKey> {
     txs: Arc<Txs<InstrumentKey>>,
     rxs: Rxs<InstrumentKey>,
@@ -880,6 +1304,7 @@ where
     }
 }
 
+#[derive(Debug)]
 struct Txs<InstrumentKey> {
     trades: FnvHashMap<ExchangeId, UnboundedTx<MarketStreamResult<InstrumentKey, PublicTrade>>>,
     l1s: FnvHashMap<ExchangeId, UnboundedTx<MarketStreamResult<InstrumentKey, OrderBookL1>>>,
@@ -899,6 +1324,18 @@ impl<InstrumentKey> Default for Txs<InstrumentKey> {
     }
 }
 
+impl<InstrumentKey> Txs<InstrumentKey> {
+    fn contains(&self, exchange: ExchangeId, sub_kind: SubKind) -> bool {
+        match sub_kind {
+            SubKind::PublicTrades => self.trades.contains_key(&exchange),
+            SubKind::OrderBooksL1 => self.l1s.contains_key(&exchange),
+            SubKind::OrderBooksL2 => self.l2s.contains_key(&exchange),
+            SubKind::Liquidations => self.liquidations.contains_key(&exchange),
+            _ => false,
+        }
+    }
+}
+
 struct Rxs<InstrumentKey> {
     trades: FnvHashMap<ExchangeId, UnboundedRx<MarketStreamResult<InstrumentKey, PublicTrade>>>,
     l1s: FnvHashMap<ExchangeId, UnboundedRx<MarketStreamResult<InstrumentKey, OrderBookL1>>>,
@@ -917,3 +1354,169 @@ impl<InstrumentKey> Default for Rxs<InstrumentKey> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::instrument::market_data::{
+        MarketDataInstrument, kind::MarketDataInstrumentKind,
+    };
+
+    fn handle(
+        txs: Txs<MarketDataInstrument>,
+        init_stream: InitStreamFn<MarketDataInstrument>,
+    ) -> DynamicStreamsHandle<MarketDataInstrument> {
+        DynamicStreamsHandle::new(Arc::new(txs), init_stream)
+    }
+
+    fn sub(
+        exchange: ExchangeId,
+        base: &str,
+        kind: SubKind,
+    ) -> Subscription<ExchangeId, MarketDataInstrument, SubKind> {
+        Subscription::new(
+            exchange,
+            MarketDataInstrument::new(base, "usdt", MarketDataInstrumentKind::Spot),
+            kind,
+        )
+    }
+
+    fn init_pending_stream(
+        _: ExchangeId,
+        _: SubKind,
+        _: Vec<Subscription<ExchangeId, MarketDataInstrument, SubKind>>,
+        _: Arc<Txs<MarketDataInstrument>>,
+    ) -> BoxFuture<'static, Result<JoinHandle<()>, DataError>> {
+        Box::pin(async { Ok(tokio::spawn(futures::future::pending())) })
+    }
+
+    #[tokio::test]
+    async fn test_dynamic_streams_handle_subscribe_unsubscribe() {
+        let mut txs = Txs::default();
+        let (tx, _rx) = mpsc_unbounded();
+        txs.trades.insert(ExchangeId::BinanceSpot, tx);
+        let handle = handle(txs, init_pending_stream);
+
+        // Subscribe to batches for an ExchangeId & SubKind the DynamicStreams was initialised with
+        let first = handle
+            .subscribe([sub(ExchangeId::BinanceSpot, "btc", SubKind::PublicTrades)])
+            .await
+            .unwrap();
+        let second = handle
+            .subscribe([sub(ExchangeId::BinanceSpot, "eth", SubKind::PublicTrades)])
+            .await
+            .unwrap();
+        assert_eq!(first, DynamicBatchId(0));
+        assert_eq!(second, DynamicBatchId(1));
+        assert_eq!(handle.batches(), vec![first, second]);
+
+        // Unsubscribe aborts every Stream in the batch
+        let abort_handles = handle.batches.lock().active.get(&first).unwrap().clone();
+        assert!(handle.unsubscribe(first));
+        tokio::task::yield_now().await;
+        assert!(abort_handles.iter().all(AbortHandle::is_finished));
+        assert_eq!(handle.batches(), vec![second]);
+
+        // Unsubscribing an inactive batch is a no-op
+        assert!(!handle.unsubscribe(first));
+    }
+
+    #[tokio::test]
+    async fn test_dynamic_streams_init_unsupported() {
+        // Kucoin Subscriptions are only supported by DynamicStreams::init_with_handle
+        let actual =
+            DynamicStreams::init([[sub(ExchangeId::Kucoin, "btc", SubKind::PublicTrades)]])
+                .await
+                .map(|_| ());
+
+        assert_eq!(
+            actual,
+            Err(DataError::Unsupported {
+                exchange: ExchangeId::Kucoin,
+                sub_kind: SubKind::PublicTrades,
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn test_dynamic_streams_handle_subscribe_errors() {
+        let mut txs = Txs::default();
+        let (tx, _rx) = mpsc_unbounded();
+        txs.trades.insert(ExchangeId::BinanceSpot, tx);
+        let handle = handle(txs, init_pending_stream);
+
+        struct TestCase {
+            input: Vec<Subscription<ExchangeId, MarketDataInstrument, SubKind>>,
+            expected: DataError,
+        }
+
+        let tests = vec![
+            TestCase {
+                // TC0: empty batch
+                input: vec![],
+                expected: DataError::SubscriptionsEmpty,
+            },
+            TestCase {
+                // TC1: DynamicStreams not initialised with an ExchangeId Stream
+                input: vec![sub(ExchangeId::Okx, "btc", SubKind::PublicTrades)],
+                expected: DataError::DynamicStreamMissing {
+                    exchange: ExchangeId::Okx,
+                    sub_kind: SubKind::PublicTrades,
+                },
+            },
+            TestCase {
+                // TC2: DynamicStreams not initialised with a SubKind Stream
+                input: vec![
+                    sub(ExchangeId::BinanceSpot, "btc", SubKind::PublicTrades),
+                    sub(ExchangeId::BinanceSpot, "btc", SubKind::OrderBooksL1),
+                ],
+                expected: DataError::DynamicStreamMissing {
+                    exchange: ExchangeId::BinanceSpot,
+                    sub_kind: SubKind::OrderBooksL1,
+                },
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = handle.subscribe(test.input).await;
+            assert_eq!(actual, Err(test.expected), "TC{} failed", index);
+        }
+
+        assert!(handle.batches().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_init_batch_stream_init_failure() {
+        fn init_stream(
+            exchange: ExchangeId,
+            sub_kind: SubKind,
+            _: Vec<Subscription<ExchangeId, MarketDataInstrument, SubKind>>,
+            _: Arc<Txs<MarketDataInstrument>>,
+        ) -> BoxFuture<'static, Result<JoinHandle<()>, DataError>> {
+            Box::pin(async move {
+                match sub_kind {
+                    SubKind::PublicTrades => Ok(tokio::spawn(futures::future::pending())),
+                    _ => Err(DataError::Unsupported { exchange, sub_kind }),
+                }
+            })
+        }
+
+        let actual = init_batch(
+            init_stream,
+            vec![
+                sub(ExchangeId::BinanceSpot, "btc", SubKind::PublicTrades),
+                sub(ExchangeId::BinanceSpot, "btc", SubKind::OrderBooksL1),
+            ],
+            &Arc::new(Txs::default()),
+        )
+        .await;
+
+        assert_eq!(
+            actual.unwrap_err(),
+            DataError::Unsupported {
+                exchange: ExchangeId::BinanceSpot,
+                sub_kind: SubKind::OrderBooksL1,
+            }
+        );
+    }
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "fault-injection")]
+use crate::streams::fault::FaultInjection;
 use crate::{
     Identifier, MarketStream,
     error::DataError,
@@ -79,6 +81,54 @@ where
     .with_reconnection_events(exchange))
 }
 
+/// Initialises a [`reconnecting`](`ReconnectingStream`) [`MarketStream`] using a collection of
+/// [`Subscription`]s, injecting the faults configured by the provided [`FaultInjection`].
+///
+/// Useful for deliberately exercising reconnection and OrderBook re-sync logic in tests. See
+/// [`init_market_stream`] for details.
+#[cfg(feature = "fault-injection")]
+pub async fn init_market_stream_with_fault_injection<Exchange, Instrument, Kind>(
+    policy: ReconnectionBackoffPolicy,
+    faults: FaultInjection,
+    subscriptions: Vec<Subscription<Exchange, Instrument, Kind>>,
+) -> Result<impl Stream<Item = MarketStreamResult<Instrument::Key, Kind::Event>>, DataError>
+where
+    Exchange: StreamSelector<Instrument, Kind>,
+    Instrument: InstrumentData + Display,
+    Kind: SubscriptionKind + Display,
+    Kind::Event: Clone,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    // Determine ExchangeId associated with these Subscriptions
+    let exchange = Exchange::ID;
+
+    // Determine StreamKey for use in logging
+    let stream_key = subscriptions
+        .first()
+        .map(|sub| StreamKey::new("market_stream", exchange, Some(sub.kind.as_str())))
+        .ok_or(DataError::SubscriptionsEmpty)?;
+
+    info!(
+        %exchange,
+        subscriptions = %display_subscriptions_without_exchange(&subscriptions),
+        ?policy,
+        ?faults,
+        ?stream_key,
+        "MarketStream with auto reconnect initialising"
+    );
+
+    Ok(init_reconnecting_stream(move || {
+        let subscriptions = subscriptions.clone();
+        async move { Exchange::Stream::init::<Exchange::SnapFetcher>(&subscriptions).await }
+    })
+    .await?
+    .with_reconnect_backoff(policy, stream_key)
+    .with_fault_injection(faults, stream_key)
+    .with_termination_on_error(|error| error.is_terminal(), stream_key)
+    .with_reconnection_events(exchange))
+}
+
 #[derive(
     Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Constructor,
 )]
@@ -0,0 +1,271 @@
+use crate::streams::consumer::StreamKey;
+use futures::Stream;
+use futures_util::StreamExt;
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+use tracing::{debug, warn};
+
+/// Fault injection configuration for deliberately exercising `MarketStream` failure handling
+/// (eg/ reconnections, OrderBook re-syncs) via
+/// [`ReconnectingStream::with_fault_injection`](super::reconnect::stream::ReconnectingStream::with_fault_injection).
+///
+/// Each probability is evaluated independently for every item yielded by the inner `Stream`,
+/// and is clamped to the range `[0.0, 1.0]` (see [`FaultInjection::clamped`]). The [`Default`]
+/// configuration injects no faults.
+///
+/// This is intended for testing only, and should never be enabled in production.
+#[derive(Debug, Copy, Clone, PartialEq, PartialOrd, Default, Deserialize, Serialize)]
+pub struct FaultInjection {
+    /// Probability of terminating the inner `Stream`, forcing a reconnection.
+    pub disconnect_probability: f64,
+
+    /// Probability of delaying an item by a random duration up to `delay_ms_max`.
+    pub delay_probability: f64,
+
+    /// Maximum millisecond duration an item may be delayed by.
+    pub delay_ms_max: u64,
+
+    /// Probability of yielding an `Ok` item twice.
+    pub duplicate_probability: f64,
+
+    /// Probability of holding back an item so that it is yielded after the next item.
+    pub reorder_probability: f64,
+
+    /// Optional random number generator seed, used to make the injected faults reproducible.
+    pub seed: Option<u64>,
+}
+
+impl FaultInjection {
+    /// Returns true if any fault may be injected with this configuration.
+    pub fn is_enabled(&self) -> bool {
+        [
+            self.disconnect_probability,
+            self.delay_probability,
+            self.duplicate_probability,
+            self.reorder_probability,
+        ]
+        .into_iter()
+        .any(|probability| probability > 0.0)
+    }
+
+    /// Returns this configuration with every probability clamped to the range `[0.0, 1.0]`,
+    /// treating a `NaN` probability as `0.0`.
+    pub fn clamped(self) -> Self {
+        let clamp = |probability: f64| {
+            if probability.is_nan() {
+                0.0
+            } else {
+                probability.clamp(0.0, 1.0)
+            }
+        };
+
+        Self {
+            disconnect_probability: clamp(self.disconnect_probability),
+            delay_probability: clamp(self.delay_probability),
+            duplicate_probability: clamp(self.duplicate_probability),
+            reorder_probability: clamp(self.reorder_probability),
+            ..self
+        }
+    }
+
+    fn rng(&self) -> StdRng {
+        match self.seed {
+            Some(seed) => StdRng::seed_from_u64(seed),
+            None => StdRng::from_os_rng(),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct FaultState<Item> {
+    rng: StdRng,
+    held: Option<Vec<Item>>,
+    terminated: bool,
+}
+
+impl<Item> FaultState<Item> {
+    fn roll(&mut self, probability: f64) -> bool {
+        self.rng.random_bool(probability)
+    }
+
+    /// Terminate the `Stream`, yielding any held back items so that none are lost.
+    fn terminate(&mut self) -> Vec<Item> {
+        self.terminated = true;
+        self.held.take().unwrap_or_default()
+    }
+}
+
+/// Inject the faults configured by the provided [`FaultInjection`] into the `Stream`.
+///
+/// Returns the `Stream` unchanged if the [`FaultInjection`] is not enabled. Any item held back
+/// to be reordered is still yielded if the `Stream` ends or an injected disconnect occurs first.
+pub fn inject_faults<St, T, E>(
+    stream: St,
+    faults: FaultInjection,
+    stream_key: StreamKey,
+) -> impl Stream<Item = Result<T, E>>
+where
+    St: Stream<Item = Result<T, E>>,
+    T: Clone,
+{
+    let faults = faults.clamped();
+    if !faults.is_enabled() {
+        return futures::future::Either::Left(stream);
+    }
+
+    let state = FaultState {
+        rng: faults.rng(),
+        held: None,
+        terminated: false,
+    };
+
+    futures::future::Either::Right(
+        futures::stream::unfold(
+            (Box::pin(stream), state),
+            move |(mut stream, mut state)| async move {
+                if state.terminated {
+                    return None;
+                }
+
+                let items = match stream.next().await {
+                    None => state.terminate(),
+                    Some(_) if state.roll(faults.disconnect_probability) => {
+                        warn!(?stream_key, "fault injection terminating MarketStream");
+                        state.terminate()
+                    }
+                    Some(result) => {
+                        let mut items = Vec::with_capacity(2);
+                        if let Ok(item) = &result
+                            && state.roll(faults.duplicate_probability)
+                        {
+                            debug!(?stream_key, "fault injection duplicating MarketStream item");
+                            items.push(Ok(item.clone()));
+                        }
+                        items.push(result);
+
+                        // Yield any held back items after this one, or otherwise maybe hold this
+                        // one back
+                        if let Some(held) = state.held.take() {
+                            items.extend(held);
+                        } else if state.roll(faults.reorder_probability) {
+                            debug!(?stream_key, "fault injection reordering MarketStream item");
+                            state.held = Some(std::mem::take(&mut items));
+                        }
+
+                        if state.roll(faults.delay_probability) {
+                            let delay = state.rng.random_range(0..=faults.delay_ms_max);
+                            tokio::time::sleep(Duration::from_millis(delay)).await;
+                        }
+
+                        items
+                    }
+                };
+
+                Some((futures::stream::iter(items), (stream, state)))
+            },
+        )
+        .flatten(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use barter_instrument::exchange::ExchangeId;
+
+    #[tokio::test]
+    async fn test_inject_faults() {
+        struct TestCase {
+            input: Vec<Result<u64, ()>>,
+            faults: FaultInjection,
+            expected: Vec<Result<u64, ()>>,
+        }
+
+        let input = vec![Ok(1), Ok(2), Err(()), Ok(3)];
+
+        let tests = vec![
+            TestCase {
+                // TC0: default FaultInjection yields the Stream unchanged
+                input: input.clone(),
+                faults: FaultInjection::default(),
+                expected: vec![Ok(1), Ok(2), Err(()), Ok(3)],
+            },
+            TestCase {
+                // TC1: certain disconnect terminates the Stream immediately
+                input: input.clone(),
+                faults: FaultInjection {
+                    disconnect_probability: 1.0,
+                    seed: Some(0),
+                    ..FaultInjection::default()
+                },
+                expected: vec![],
+            },
+            TestCase {
+                // TC2: certain duplication yields every Ok item twice
+                input: input.clone(),
+                faults: FaultInjection {
+                    duplicate_probability: 1.0,
+                    seed: Some(0),
+                    ..FaultInjection::default()
+                },
+                expected: vec![Ok(1), Ok(1), Ok(2), Ok(2), Err(()), Ok(3), Ok(3)],
+            },
+            TestCase {
+                // TC3: certain reordering swaps every pair of items
+                input: input.clone(),
+                faults: FaultInjection {
+                    reorder_probability: 1.0,
+                    seed: Some(0),
+                    ..FaultInjection::default()
+                },
+                expected: vec![Ok(2), Ok(1), Ok(3), Err(())],
+            },
+            TestCase {
+                // TC4: certain reordering of an odd number of items still yields the last item
+                input: vec![Ok(1), Ok(2), Ok(3)],
+                faults: FaultInjection {
+                    reorder_probability: 1.0,
+                    seed: Some(0),
+                    ..FaultInjection::default()
+                },
+                expected: vec![Ok(2), Ok(1), Ok(3)],
+            },
+            TestCase {
+                // TC5: NaN & out of range probabilities are clamped
+                input: vec![Ok(1), Ok(2), Ok(3)],
+                faults: FaultInjection {
+                    disconnect_probability: f64::NAN,
+                    duplicate_probability: 2.0,
+                    reorder_probability: -1.0,
+                    seed: Some(0),
+                    ..FaultInjection::default()
+                },
+                expected: vec![Ok(1), Ok(1), Ok(2), Ok(2), Ok(3), Ok(3)],
+            },
+            TestCase {
+                // TC6: certain delay yields every item in order
+                input: input.clone(),
+                faults: FaultInjection {
+                    delay_probability: 1.0,
+                    delay_ms_max: 1,
+                    seed: Some(0),
+                    ..FaultInjection::default()
+                },
+                expected: vec![Ok(1), Ok(2), Err(()), Ok(3)],
+            },
+        ];
+
+        for (index, test) in tests.into_iter().enumerate() {
+            let actual = inject_faults(
+                futures::stream::iter(test.input),
+                test.faults,
+                StreamKey::new_general("test", ExchangeId::Simulated),
+            )
+            .collect::<Vec<_>>()
+            .await;
+
+            assert_eq!(actual, test.expected, "TC{} failed", index);
+        }
+    }
+}
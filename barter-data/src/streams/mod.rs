@@ -13,6 +13,11 @@ pub mod builder;
 /// drive a re-connecting [`MarketStream`](super::MarketStream).
 pub mod consumer;
 
+/// Fault injection configuration and logic for deliberately exercising `MarketStream` failure
+/// handling, such as reconnections and OrderBook re-syncs.
+#[cfg(feature = "fault-injection")]
+pub mod fault;
+
 /// Defines a [`ReconnectingStream`](reconnect::stream::ReconnectingStream) and associated logic
 /// for generating an auto reconnecting `Stream`.
 pub mod reconnect;
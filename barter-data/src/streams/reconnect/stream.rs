@@ -1,3 +1,5 @@
+#[cfg(feature = "fault-injection")]
+use crate::streams::fault::{FaultInjection, inject_faults};
 use crate::streams::{consumer::StreamKey, reconnect::Event};
 use barter_integration::channel::Tx;
 use derive_more::Constructor;
@@ -83,6 +85,23 @@ where
         })
     }
 
+    /// Injects the faults configured by the provided [`FaultInjection`] (eg/ disconnects, delayed,
+    /// duplicated & reordered items) into every inner [`Stream`]. An injected disconnect
+    /// terminates the inner [`Stream`], causing the [`ReconnectingStream`] to re-initialise it.
+    #[cfg(feature = "fault-injection")]
+    fn with_fault_injection<St, T, E>(
+        self,
+        faults: FaultInjection,
+        stream_key: StreamKey,
+    ) -> impl Stream<Item = impl Stream<Item = Result<T, E>>>
+    where
+        Self: Stream<Item = St>,
+        St: Stream<Item = Result<T, E>>,
+        T: Clone,
+    {
+        self.map(move |stream| inject_faults(stream, faults, stream_key))
+    }
+
     /// Maps every [`ReconnectingStream`] `Stream::Item` into an [`reconnect::Event::Item`](Event),
     /// and chain a [`reconnect::Event::Reconnecting`](Event)
     fn with_reconnection_events<St, Origin>(
@@ -4,7 +4,7 @@ use self::{
 };
 use crate::{
     Identifier,
-    exchange::Connector,
+    exchange::{Connector, connector_url},
     instrument::InstrumentData,
     subscription::{Map, Subscription, SubscriptionKind, SubscriptionMeta},
 };
@@ -15,7 +15,7 @@ use barter_integration::{
 };
 use futures::SinkExt;
 use serde::{Deserialize, Serialize};
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 use tracing::debug;
 
 /// [`SubscriptionMapper`] implementations defining how to map a
@@ -47,6 +47,9 @@ pub struct Subscribed<InstrumentKey> {
     pub websocket: WebSocket,
     pub map: Map<InstrumentKey>,
     pub buffered_websocket_events: Vec<WsMessage>,
+    /// Server provided ping interval for this connection, which overrides the
+    /// [`Connector::ping_interval`] duration.
+    pub ping_interval: Option<Duration>,
 }
 
 /// Standard [`Subscriber`] for [`WebSocket`]s suitable for most exchanges.
@@ -69,41 +72,184 @@ impl Subscriber for WebSocketSubscriber {
     {
         // Define variables for logging ergonomics
         let exchange = Exchange::ID;
-        let url = Exchange::url()?;
+        let url = connector_url::<Exchange>()?;
         debug!(%exchange, %url, ?subscriptions, "subscribing to WebSocket");
 
         // Connect to exchange
-        let mut websocket = connect(url).await?;
+        let websocket = connect(url).await?;
         debug!(%exchange, ?subscriptions, "connected to WebSocket");
 
-        // Map &[Subscription<Exchange, Kind>] to SubscriptionMeta
-        let SubscriptionMeta {
-            instrument_map,
-            ws_subscriptions,
-        } = Self::SubMapper::map::<Exchange, Instrument, Kind>(subscriptions);
-
-        // Send Subscriptions over WebSocket
-        for subscription in ws_subscriptions {
-            debug!(%exchange, payload = ?subscription, "sending exchange subscription");
-            websocket
-                .send(subscription)
-                .await
-                .map_err(|error| SocketError::WebSocket(Box::new(error)))?;
+        subscribe_websocket::<Exchange, Instrument, Kind>(websocket, subscriptions).await
+    }
+}
+
+/// Action [`Subscription`]s over an already connected [`WebSocket`], and validate the exchange
+/// accepted them.
+///
+/// Useful for custom [`Subscriber`]s that must perform exchange specific steps before
+/// subscriptions can be sent (eg/ `Kucoin` requires a connection token and welcome message).
+pub async fn subscribe_websocket<Exchange, Instrument, Kind>(
+    mut websocket: WebSocket,
+    subscriptions: &[Subscription<Exchange, Instrument, Kind>],
+) -> Result<Subscribed<Instrument::Key>, SocketError>
+where
+    Exchange: Connector + Send + Sync,
+    Kind: SubscriptionKind + Send + Sync,
+    Instrument: InstrumentData,
+    Subscription<Exchange, Instrument, Kind>:
+        Identifier<Exchange::Channel> + Identifier<Exchange::Market>,
+{
+    let exchange = Exchange::ID;
+
+    // Map &[Subscription<Exchange, Kind>] to SubscriptionMeta
+    let SubscriptionMeta {
+        instrument_map,
+        ws_subscriptions,
+    } = WebSocketSubMapper::map::<Exchange, Instrument, Kind>(subscriptions);
+
+    // Send Subscriptions over WebSocket, pacing them if the exchange enforces a rate limit
+    let rate_limit = Exchange::subscribe_rate_limit();
+    for (index, subscription) in ws_subscriptions.into_iter().enumerate() {
+        if let Some(rate_limit) = rate_limit.filter(|limit| limit.requires_wait(index)) {
+            debug!(%exchange, ?rate_limit, "pacing exchange subscriptions to respect rate limit");
+            tokio::time::sleep(rate_limit.interval).await;
+        }
+
+        debug!(%exchange, payload = ?subscription, "sending exchange subscription");
+        websocket
+            .send(subscription)
+            .await
+            .map_err(|error| SocketError::WebSocket(Box::new(error)))?;
+    }
+
+    // Validate Subscription responses
+    let (map, buffered_websocket_events) = Exchange::SubValidator::validate::<
+        Exchange,
+        Instrument::Key,
+        Kind,
+    >(instrument_map, &mut websocket)
+    .await?;
+
+    debug!(%exchange, "successfully initialised WebSocket stream with confirmed Subscriptions");
+    Ok(Subscribed {
+        websocket,
+        map,
+        buffered_websocket_events,
+        ping_interval: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        exchange::okx::{MAX_ARGS_PER_REQUEST_OKX, Okx},
+        subscription::trade::PublicTrades,
+    };
+    use barter_instrument::instrument::market_data::kind::MarketDataInstrumentKind;
+    use futures::StreamExt;
+    use parking_lot::Mutex;
+    use serde_json::{Value, json};
+    use std::sync::Arc;
+    use tokio::{net::TcpListener, time::Instant};
+    use tokio_tungstenite::{accept_async, tungstenite::Message};
+
+    /// Shared buffer capturing formatted log output.
+    #[derive(Clone, Default)]
+    struct LogBuffer(Arc<Mutex<Vec<u8>>>);
+
+    impl std::io::Write for LogBuffer {
+        fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+            self.0.lock().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> std::io::Result<()> {
+            Ok(())
         }
+    }
+
+    /// Start a local WebSocket server that confirms every [`Okx`] subscription argument,
+    /// returning its url and the time each subscription request was received.
+    async fn start_okx_server() -> (String, Arc<Mutex<Vec<Instant>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+
+        let received = Arc::clone(&requests);
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut websocket = accept_async(stream).await.unwrap();
+
+            while let Some(Ok(Message::Text(request))) = websocket.next().await {
+                received.lock().push(Instant::now());
+
+                let request = serde_json::from_str::<Value>(&request).unwrap();
+                for arg in request["args"].as_array().into_iter().flatten() {
+                    let response = json!({ "event": "subscribe", "arg": arg });
+                    if websocket
+                        .send(Message::text(response.to_string()))
+                        .await
+                        .is_err()
+                    {
+                        return;
+                    }
+                }
+            }
+        });
+
+        (url, requests)
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_websocket_paces_subscriptions() {
+        let logs = LogBuffer::default();
+        let writer = logs.clone();
+        let _guard = tracing::subscriber::set_default(
+            tracing_subscriber::fmt()
+                .with_max_level(tracing::Level::DEBUG)
+                .with_writer(move || writer.clone())
+                .finish(),
+        );
+
+        let (url, requests) = start_okx_server().await;
+        let rate_limit = Okx::subscribe_rate_limit().unwrap();
+
+        // Enough subscriptions to require one more request than the rate limit allows per interval
+        let num_requests = rate_limit.max_messages.get() + 1;
+        let subscriptions = (0..(num_requests - 1) * MAX_ARGS_PER_REQUEST_OKX + 1)
+            .map(|index| {
+                Subscription::from((
+                    Okx,
+                    format!("coin{index}"),
+                    "usdt".to_string(),
+                    MarketDataInstrumentKind::Spot,
+                    PublicTrades,
+                ))
+            })
+            .collect::<Vec<_>>();
+
+        let websocket = connect(url).await.unwrap();
+        let subscribed = subscribe_websocket(websocket, &subscriptions)
+            .await
+            .unwrap();
+        assert_eq!(subscribed.map.0.len(), subscriptions.len());
+
+        let requests = requests.lock().clone();
+        assert_eq!(requests.len(), num_requests);
+
+        // Requests within the rate limit are sent immediately
+        let (first, last) = (requests[0], requests[num_requests - 2]);
+        assert!(last.duration_since(first) < rate_limit.interval);
+
+        // Requests exceeding the rate limit wait for the next interval
+        assert!(requests[num_requests - 1].duration_since(first) >= rate_limit.interval);
 
-        // Validate Subscription responses
-        let (map, buffered_websocket_events) = Exchange::SubValidator::validate::<
-            Exchange,
-            Instrument::Key,
-            Kind,
-        >(instrument_map, &mut websocket)
-        .await?;
-
-        debug!(%exchange, "successfully initialised WebSocket stream with confirmed Subscriptions");
-        Ok(Subscribed {
-            websocket,
-            map,
-            buffered_websocket_events,
-        })
+        let logs = String::from_utf8(logs.0.lock().clone()).unwrap();
+        assert_eq!(
+            logs.matches("pacing exchange subscriptions to respect rate limit")
+                .count(),
+            1
+        );
     }
 }
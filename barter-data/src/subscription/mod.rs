@@ -195,7 +195,7 @@ pub fn exchange_supports_instrument_kind(
         // Spot
         (
             BinanceFuturesUsd | Bitmex | BybitPerpetualsUsd | GateioPerpetualsUsd
-            | GateioPerpetualsBtc,
+            | GateioPerpetualsBtc | Hyperliquid,
             Spot,
         ) => false,
         (_, Spot) => true,
@@ -207,7 +207,7 @@ pub fn exchange_supports_instrument_kind(
         // Perpetual
         (
             BinanceFuturesUsd | Bitmex | Okx | BybitPerpetualsUsd | GateioPerpetualsUsd
-            | GateioPerpetualsBtc,
+            | GateioPerpetualsBtc | Hyperliquid,
             Perpetual,
         ) => true,
         (_, Perpetual) => false,
@@ -265,14 +265,20 @@ pub fn exchange_supports_instrument_kind_sub_kind(
         (BybitSpot, Spot, PublicTrades | OrderBooksL1 | OrderBooksL2) => true,
         (BybitPerpetualsUsd, Perpetual, PublicTrades | OrderBooksL1 | OrderBooksL2) => true,
         (Coinbase, Spot, PublicTrades) => true,
-        (GateioSpot, Spot, PublicTrades) => true,
+        (GateioSpot, Spot, PublicTrades | OrderBooksL1) => true,
         (GateioFuturesUsd, Future { .. }, PublicTrades) => true,
         (GateioFuturesBtc, Future { .. }, PublicTrades) => true,
-        (GateioPerpetualsUsd, Perpetual, PublicTrades) => true,
-        (GateioPerpetualsBtc, Perpetual, PublicTrades) => true,
+        (GateioPerpetualsUsd, Perpetual, PublicTrades | OrderBooksL1) => true,
+        (GateioPerpetualsBtc, Perpetual, PublicTrades | OrderBooksL1) => true,
         (GateioOptions, Option { .. }, PublicTrades) => true,
+        (Hyperliquid, Perpetual, PublicTrades | OrderBooksL1 | OrderBooksL2) => true,
         (Kraken, Spot, PublicTrades | OrderBooksL1) => true,
-        (Okx, Spot | Future { .. } | Perpetual | Option { .. }, PublicTrades) => true,
+        (Kucoin, Spot, PublicTrades | OrderBooksL1) => true,
+        (
+            Okx,
+            Spot | Future { .. } | Perpetual | Option { .. },
+            PublicTrades | OrderBooksL1 | Liquidations,
+        ) => true,
 
         (_, _, _) => false,
     }
@@ -337,7 +343,7 @@ mod tests {
         use super::*;
         use crate::{
             exchange::{coinbase::Coinbase, okx::Okx},
-            subscription::trade::PublicTrades,
+            subscription::{liquidation::Liquidations, trade::PublicTrades},
         };
         use barter_instrument::instrument::market_data::MarketDataInstrument;
 
@@ -548,6 +554,42 @@ mod tests {
                 }
             }
         }
+
+        #[test]
+        fn test_validate_okx_liquidations() {
+            struct TestCase {
+                input: Subscription<Okx, MarketDataInstrument, Liquidations>,
+            }
+
+            let tests = vec![
+                TestCase {
+                    // TC0: Valid Okx Spot Liquidations subscription (MARGIN instType)
+                    input: Subscription::from((
+                        Okx,
+                        "base",
+                        "quote",
+                        MarketDataInstrumentKind::Spot,
+                        Liquidations,
+                    )),
+                },
+                TestCase {
+                    // TC1: Valid Okx Perpetual Liquidations subscription
+                    input: Subscription::from((
+                        Okx,
+                        "base",
+                        "quote",
+                        MarketDataInstrumentKind::Perpetual,
+                        Liquidations,
+                    )),
+                },
+            ];
+
+            for (index, test) in tests.into_iter().enumerate() {
+                let expected = test.input.clone();
+                let actual = test.input.validate();
+                assert_eq!(actual.unwrap(), expected, "TC{} failed", index);
+            }
+        }
     }
 
     mod instrument_map {
@@ -0,0 +1,314 @@
+//! Local mock exchange WebSocket server for end-to-end `MarketStream` tests.
+//!
+//! A [`MockExchangeServer`] speaks an exchange specific [`MockDialect`] (subscription responses
+//! and application-level pongs), then replays a scripted [`MockConnection`] of raw frames for
+//! each accepted connection. Scripting a connection to close after its frames exercises the
+//! reconnection logic of `init_market_stream` without real exchange connectivity.
+//!
+//! Exchanges that are generic over an [`ExchangeServer`](barter_data::exchange::ExchangeServer)
+//! (eg/ `Binance<Server>`, `Bybit<Server>`) are pointed at a [`MockExchangeServer`] using a
+//! server type generated by the [`mock_exchange_server!`] macro. Exchanges constructed internally
+//! (eg/ by `DynamicStreams`) are pointed at a [`MockExchangeServer`] via
+//! [`override_url`](barter_data::exchange::override_url).
+
+// Shared by several integration test crates, each of which only uses a subset
+#![allow(dead_code)]
+
+use barter_data::{
+    event::MarketEvent,
+    streams::{
+        consumer::MarketStreamResult,
+        reconnect::{Event, stream::ReconnectionBackoffPolicy},
+    },
+};
+use futures::{SinkExt, Stream, StreamExt};
+use serde_json::{Value, json};
+use std::{
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+use tokio::net::{TcpListener, TcpStream};
+use tokio_tungstenite::{accept_async, tungstenite::Message};
+
+/// Fast [`ReconnectionBackoffPolicy`] so reconnection tests complete quickly.
+pub const TEST_RECONNECTION_POLICY: ReconnectionBackoffPolicy = ReconnectionBackoffPolicy {
+    backoff_ms_initial: 10,
+    backoff_multiplier: 2,
+    backoff_ms_max: 100,
+};
+
+/// Maximum time to wait for the next stream event before failing the test.
+pub const TEST_EVENT_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Wait for the next `MarketStream` event, failing the test if it times out or the stream ends.
+pub async fn next_event<St, InstrumentKey, Kind>(
+    stream: &mut St,
+) -> MarketStreamResult<InstrumentKey, Kind>
+where
+    St: Stream<Item = MarketStreamResult<InstrumentKey, Kind>> + Unpin,
+{
+    tokio::time::timeout(TEST_EVENT_TIMEOUT, stream.next())
+        .await
+        .expect("timed out waiting for MarketStream event")
+        .expect("MarketStream ended unexpectedly")
+}
+
+/// Wait for the next [`MarketEvent`], skipping any non-terminal errors (eg/ unparsable pongs)
+/// in the same way consumers of a `MarketStream` do.
+pub async fn next_item<St, InstrumentKey, Kind>(stream: &mut St) -> MarketEvent<InstrumentKey, Kind>
+where
+    St: Stream<Item = MarketStreamResult<InstrumentKey, Kind>> + Unpin,
+    InstrumentKey: std::fmt::Debug,
+    Kind: std::fmt::Debug,
+{
+    loop {
+        match next_event(stream).await {
+            Event::Item(Ok(event)) => break event,
+            Event::Item(Err(error)) if !error.is_terminal() => continue,
+            other => panic!("expected MarketEvent, but received: {other:?}"),
+        }
+    }
+}
+
+/// Raw Binance trade frame for the provided symbol (eg/ "BTCUSDT").
+pub fn binance_trade(symbol: &str, id: u64, price: &str) -> Value {
+    json!({
+        "e": "trade",
+        "E": 1649324825173u64,
+        "s": symbol,
+        "t": id,
+        "p": price,
+        "q": "0.5",
+        "b": 1,
+        "a": 2,
+        "T": 1649324825173u64,
+        "m": false,
+        "M": true
+    })
+}
+
+/// Generate an [`ExchangeServer`](barter_data::exchange::ExchangeServer) whose WebSocket url is
+/// bound at runtime to a [`MockExchangeServer`].
+///
+/// Each test should generate its own server type, since the url is stored in a static.
+#[macro_export]
+macro_rules! mock_exchange_server {
+    ($server:ident, $exchange:expr) => {
+        #[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Debug, Default)]
+        pub struct $server;
+
+        impl $server {
+            fn url() -> &'static std::sync::OnceLock<String> {
+                static URL: std::sync::OnceLock<String> = std::sync::OnceLock::new();
+                &URL
+            }
+
+            /// Bind this server type to the provided [`MockExchangeServer`] url.
+            pub fn bind(url: &str) {
+                Self::url()
+                    .set(url.to_string())
+                    .expect("mock exchange server url already bound");
+            }
+        }
+
+        impl barter_data::exchange::ExchangeServer for $server {
+            const ID: barter_instrument::exchange::ExchangeId = $exchange;
+
+            fn websocket_url() -> &'static str {
+                Self::url()
+                    .get()
+                    .expect("mock exchange server url not bound")
+                    .as_str()
+            }
+        }
+    };
+}
+
+/// Exchange specific request/response dialect spoken by a [`MockExchangeServer`].
+pub trait MockDialect: Send + Sync + 'static {
+    /// Frames sent in response to a client request, eg/ subscription responses & pongs.
+    fn respond(request: &Value) -> Vec<Value>;
+
+    /// Returns true if the client request is a subscription request. Scripted frames are only
+    /// replayed once a connection has been subscribed.
+    fn is_subscribe(request: &Value) -> bool;
+}
+
+/// Binance dialect.
+///
+/// See docs: <https://binance-docs.github.io/apidocs/spot/en/#live-subscribing-unsubscribing-to-streams>
+#[derive(Debug)]
+pub struct BinanceDialect;
+
+impl MockDialect for BinanceDialect {
+    fn respond(request: &Value) -> Vec<Value> {
+        if Self::is_subscribe(request) {
+            vec![json!({ "result": null, "id": request["id"] })]
+        } else {
+            vec![]
+        }
+    }
+
+    fn is_subscribe(request: &Value) -> bool {
+        request["method"] == "SUBSCRIBE"
+    }
+}
+
+/// Bybit dialect.
+///
+/// See docs: <https://bybit-exchange.github.io/docs/v5/ws/connect>
+#[derive(Debug)]
+pub struct BybitDialect;
+
+impl MockDialect for BybitDialect {
+    fn respond(request: &Value) -> Vec<Value> {
+        match request["op"].as_str() {
+            Some("subscribe") => vec![json!({
+                "success": true,
+                "ret_msg": "subscribe",
+                "conn_id": "mock",
+                "op": "subscribe"
+            })],
+            Some("ping") => vec![json!({
+                "success": true,
+                "ret_msg": "pong",
+                "conn_id": "mock",
+                "op": "ping"
+            })],
+            _ => vec![],
+        }
+    }
+
+    fn is_subscribe(request: &Value) -> bool {
+        request["op"] == "subscribe"
+    }
+}
+
+/// Scripted behaviour of a single [`MockExchangeServer`] connection.
+#[derive(Clone, Debug)]
+pub struct MockConnection {
+    /// Raw frames replayed once the connection is subscribed.
+    pub frames: Vec<Value>,
+    /// Delay before each replayed frame.
+    pub frame_interval: Duration,
+    /// How the connection ends once all frames have been replayed.
+    pub end: MockConnectionEnd,
+}
+
+/// How a [`MockConnection`] ends once all of its frames have been replayed.
+#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+pub enum MockConnectionEnd {
+    /// Send a CloseFrame, forcing the client to reconnect.
+    Close,
+    /// Keep the connection open, continuing to respond to client requests.
+    Hold,
+}
+
+impl MockConnection {
+    pub fn new(frames: Vec<Value>, end: MockConnectionEnd) -> Self {
+        Self {
+            frames,
+            frame_interval: Duration::from_millis(10),
+            end,
+        }
+    }
+}
+
+/// Local WebSocket server that mimics an exchange.
+#[derive(Debug)]
+pub struct MockExchangeServer {
+    pub url: String,
+    connections: Arc<AtomicUsize>,
+}
+
+impl MockExchangeServer {
+    /// Start a [`MockExchangeServer`] on a random local port.
+    ///
+    /// The Nth accepted connection follows the Nth [`MockConnection`] script, with the last
+    /// script reused for any subsequent connections.
+    pub async fn start<Dialect>(script: Vec<MockConnection>) -> Self
+    where
+        Dialect: MockDialect,
+    {
+        assert!(!script.is_empty(), "MockExchangeServer requires a script");
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        let accepted = Arc::clone(&connections);
+        tokio::spawn(async move {
+            while let Ok((stream, _)) = listener.accept().await {
+                let index = accepted.fetch_add(1, Ordering::SeqCst);
+                let connection = script
+                    .get(index)
+                    .unwrap_or_else(|| script.last().unwrap())
+                    .clone();
+
+                tokio::spawn(serve_connection::<Dialect>(stream, connection));
+            }
+        });
+
+        Self { url, connections }
+    }
+
+    /// Number of connections accepted so far.
+    pub fn connections(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+}
+
+async fn serve_connection<Dialect>(stream: TcpStream, connection: MockConnection)
+where
+    Dialect: MockDialect,
+{
+    let Ok(websocket) = accept_async(stream).await else {
+        return;
+    };
+    let (mut tx, mut rx) = websocket.split();
+
+    let mut frames = connection.frames.into_iter();
+    let mut replaying = false;
+    let mut replay = tokio::time::interval(connection.frame_interval);
+
+    loop {
+        tokio::select! {
+            request = rx.next() => {
+                let request = match request {
+                    Some(Ok(Message::Text(request))) => request,
+                    Some(Ok(_)) => continue,
+                    _ => return,
+                };
+
+                let Ok(request) = serde_json::from_str::<Value>(&request) else {
+                    continue;
+                };
+
+                replaying |= Dialect::is_subscribe(&request);
+
+                for response in Dialect::respond(&request) {
+                    if tx.send(Message::text(response.to_string())).await.is_err() {
+                        return;
+                    }
+                }
+            }
+            _ = replay.tick(), if replaying => {
+                match frames.next() {
+                    Some(frame) => {
+                        if tx.send(Message::text(frame.to_string())).await.is_err() {
+                            return;
+                        }
+                    }
+                    None if connection.end == MockConnectionEnd::Close => {
+                        let _ = tx.send(Message::Close(None)).await;
+                        return;
+                    }
+                    None => replaying = false,
+                }
+            }
+        }
+    }
+}
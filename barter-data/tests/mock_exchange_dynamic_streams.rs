@@ -0,0 +1,83 @@
+//! End-to-end [`DynamicStreams`] tests against a local [`MockExchangeServer`].
+//!
+//! [`DynamicStreams`] constructs the production exchange connectors internally, so their urls are
+//! redirected to the [`MockExchangeServer`] via the process wide [`override_url`]. These tests
+//! therefore live in their own test crate, isolated from any other exchange connections.
+//!
+//! Requires the `url-override` feature: `cargo test --features url-override`.
+
+mod mock_exchange;
+
+use barter_data::{
+    exchange::override_url,
+    streams::builder::dynamic::{DynamicBatchId, DynamicStreams},
+    subscription::SubKind,
+};
+use barter_instrument::{
+    exchange::ExchangeId,
+    instrument::market_data::{MarketDataInstrument, kind::MarketDataInstrumentKind},
+};
+use mock_exchange::{
+    BinanceDialect, MockConnection, MockConnectionEnd, MockExchangeServer, binance_trade, next_item,
+};
+use url::Url;
+
+#[tokio::test]
+async fn test_dynamic_streams_binance_spot_public_trades_with_runtime_subscribe() {
+    let server = MockExchangeServer::start::<BinanceDialect>(vec![
+        MockConnection::new(
+            vec![binance_trade("BTCUSDT", 1, "100.0")],
+            MockConnectionEnd::Hold,
+        ),
+        MockConnection::new(
+            vec![binance_trade("ETHUSDT", 2, "10.0")],
+            MockConnectionEnd::Hold,
+        ),
+    ])
+    .await;
+    override_url(ExchangeId::BinanceSpot, Url::parse(&server.url).unwrap());
+
+    let (mut streams, handle) = DynamicStreams::init_with_handle([[(
+        ExchangeId::BinanceSpot,
+        "btc",
+        "usdt",
+        MarketDataInstrumentKind::Spot,
+        SubKind::PublicTrades,
+    )]])
+    .await
+    .unwrap();
+    let mut trades = streams.select_trades(ExchangeId::BinanceSpot).unwrap();
+
+    let btc_trade = next_item(&mut trades).await;
+    assert_eq!(btc_trade.exchange, ExchangeId::BinanceSpot);
+    assert_eq!(
+        btc_trade.instrument,
+        MarketDataInstrument::from(("btc", "usdt", MarketDataInstrumentKind::Spot))
+    );
+    assert_eq!(btc_trade.kind.price, 100.0);
+
+    // Subscribe to a new batch at runtime, forwarding events to the existing trades Stream
+    let batch = handle
+        .subscribe([(
+            ExchangeId::BinanceSpot,
+            "eth",
+            "usdt",
+            MarketDataInstrumentKind::Spot,
+            SubKind::PublicTrades,
+        )])
+        .await
+        .unwrap();
+    assert_eq!(batch, DynamicBatchId(1));
+    assert_eq!(handle.batches(), vec![DynamicBatchId(0), DynamicBatchId(1)]);
+
+    let eth_trade = next_item(&mut trades).await;
+    assert_eq!(
+        eth_trade.instrument,
+        MarketDataInstrument::from(("eth", "usdt", MarketDataInstrumentKind::Spot))
+    );
+    assert_eq!(eth_trade.kind.price, 10.0);
+    assert_eq!(server.connections(), 2);
+
+    assert!(handle.unsubscribe(batch));
+    assert_eq!(handle.batches(), vec![DynamicBatchId(0)]);
+}
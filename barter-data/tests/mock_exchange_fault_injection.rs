@@ -0,0 +1,63 @@
+//! End-to-end `MarketStream` fault injection tests against a local [`MockExchangeServer`].
+//!
+//! Requires the `fault-injection` feature: `cargo test --features fault-injection`.
+
+mod mock_exchange;
+
+use barter_data::{
+    exchange::binance::Binance,
+    streams::{
+        consumer::init_market_stream_with_fault_injection, fault::FaultInjection, reconnect::Event,
+    },
+    subscription::{Subscription, trade::PublicTrades},
+};
+use barter_instrument::{
+    exchange::ExchangeId, instrument::market_data::kind::MarketDataInstrumentKind,
+};
+use mock_exchange::{
+    BinanceDialect, MockConnection, MockConnectionEnd, MockExchangeServer,
+    TEST_RECONNECTION_POLICY, binance_trade, next_event,
+};
+
+mock_exchange_server!(MockBinanceServerFaults, ExchangeId::BinanceSpot);
+
+#[tokio::test]
+async fn test_binance_public_trades_stream_with_injected_disconnects() {
+    let server = MockExchangeServer::start::<BinanceDialect>(vec![MockConnection::new(
+        vec![binance_trade("BTCUSDT", 1, "100.0")],
+        MockConnectionEnd::Hold,
+    )])
+    .await;
+    MockBinanceServerFaults::bind(&server.url);
+
+    let stream = init_market_stream_with_fault_injection(
+        TEST_RECONNECTION_POLICY,
+        FaultInjection {
+            disconnect_probability: 1.0,
+            seed: Some(0),
+            ..FaultInjection::default()
+        },
+        vec![
+            Subscription::<Binance<MockBinanceServerFaults>, _, _>::from((
+                Binance::default(),
+                "btc",
+                "usdt",
+                MarketDataInstrumentKind::Spot,
+                PublicTrades,
+            )),
+        ],
+    )
+    .await
+    .unwrap();
+    let mut stream = std::pin::pin!(stream);
+
+    // Every item is replaced by an injected disconnect, so the stream continually reconnects
+    for _ in 0..2 {
+        match next_event(&mut stream).await {
+            Event::Reconnecting(exchange) => assert_eq!(exchange, ExchangeId::BinanceSpot),
+            Event::Item(item) => panic!("expected Reconnecting, but received: {item:?}"),
+        }
+    }
+
+    assert!(server.connections() >= 2);
+}
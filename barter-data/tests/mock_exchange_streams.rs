@@ -0,0 +1,182 @@
+//! End-to-end `MarketStream` tests against a local [`MockExchangeServer`].
+
+mod mock_exchange;
+
+use barter_data::{
+    exchange::{binance::Binance, bybit::Bybit},
+    streams::{consumer::init_market_stream, reconnect::Event},
+    subscription::{
+        Subscription,
+        book::{OrderBookEvent, OrderBooksL2},
+        trade::PublicTrades,
+    },
+};
+use barter_instrument::{
+    exchange::ExchangeId, instrument::market_data::kind::MarketDataInstrumentKind,
+};
+use mock_exchange::{
+    BinanceDialect, BybitDialect, MockConnection, MockConnectionEnd, MockExchangeServer,
+    TEST_RECONNECTION_POLICY, binance_trade, next_event, next_item,
+};
+use serde_json::json;
+
+mock_exchange_server!(MockBinanceServerTrades, ExchangeId::BinanceSpot);
+
+#[tokio::test]
+async fn test_binance_public_trades_stream() {
+    let server = MockExchangeServer::start::<BinanceDialect>(vec![MockConnection::new(
+        vec![
+            binance_trade("BTCUSDT", 1, "100.0"),
+            binance_trade("BTCUSDT", 2, "101.0"),
+        ],
+        MockConnectionEnd::Hold,
+    )])
+    .await;
+    MockBinanceServerTrades::bind(&server.url);
+
+    let stream = init_market_stream(
+        TEST_RECONNECTION_POLICY,
+        vec![
+            Subscription::<Binance<MockBinanceServerTrades>, _, _>::from((
+                Binance::default(),
+                "btc",
+                "usdt",
+                MarketDataInstrumentKind::Spot,
+                PublicTrades,
+            )),
+        ],
+    )
+    .await
+    .unwrap();
+    let mut stream = std::pin::pin!(stream);
+
+    let trades = [next_item(&mut stream).await, next_item(&mut stream).await];
+
+    assert_eq!(trades[0].exchange, ExchangeId::BinanceSpot);
+    assert_eq!(
+        trades
+            .iter()
+            .map(|trade| trade.kind.price)
+            .collect::<Vec<_>>(),
+        vec![100.0, 101.0]
+    );
+    assert_eq!(server.connections(), 1);
+}
+
+mock_exchange_server!(MockBinanceServerReconnect, ExchangeId::BinanceSpot);
+
+#[tokio::test]
+async fn test_binance_public_trades_stream_reconnects() {
+    let server = MockExchangeServer::start::<BinanceDialect>(vec![
+        MockConnection::new(
+            vec![binance_trade("BTCUSDT", 1, "100.0")],
+            MockConnectionEnd::Close,
+        ),
+        MockConnection::new(
+            vec![binance_trade("BTCUSDT", 2, "101.0")],
+            MockConnectionEnd::Hold,
+        ),
+    ])
+    .await;
+    MockBinanceServerReconnect::bind(&server.url);
+
+    let stream = init_market_stream(
+        TEST_RECONNECTION_POLICY,
+        vec![
+            Subscription::<Binance<MockBinanceServerReconnect>, _, _>::from((
+                Binance::default(),
+                "btc",
+                "usdt",
+                MarketDataInstrumentKind::Spot,
+                PublicTrades,
+            )),
+        ],
+    )
+    .await
+    .unwrap();
+    let mut stream = std::pin::pin!(stream);
+
+    let first = next_item(&mut stream).await;
+    assert_eq!(first.kind.id, "1");
+
+    // Skip any errors yielded by the terminated connection until the stream reconnects
+    loop {
+        match next_event(&mut stream).await {
+            Event::Reconnecting(exchange) => {
+                assert_eq!(exchange, ExchangeId::BinanceSpot);
+                break;
+            }
+            Event::Item(Err(_)) => continue,
+            Event::Item(Ok(event)) => panic!("expected Reconnecting, but received: {event:?}"),
+        }
+    }
+
+    let second = next_item(&mut stream).await;
+    assert_eq!(second.kind.id, "2");
+    assert_eq!(server.connections(), 2);
+}
+
+mock_exchange_server!(MockBybitServerOrderBooksL2, ExchangeId::BybitPerpetualsUsd);
+
+#[tokio::test]
+async fn test_bybit_order_books_l2_stream() {
+    let server = MockExchangeServer::start::<BybitDialect>(vec![MockConnection::new(
+        vec![
+            json!({
+                "topic": "orderbook.50.BTCUSDT",
+                "type": "snapshot",
+                "ts": 1672304484978u64,
+                "data": {
+                    "s": "BTCUSDT",
+                    "b": [["16493.50", "0.006"]],
+                    "a": [["16611.00", "0.029"]],
+                    "u": 100,
+                    "seq": 1000
+                }
+            }),
+            json!({
+                "topic": "orderbook.50.BTCUSDT",
+                "type": "delta",
+                "ts": 1672304484980u64,
+                "data": {
+                    "s": "BTCUSDT",
+                    "b": [["16493.50", "0"]],
+                    "a": [],
+                    "u": 101,
+                    "seq": 1001
+                }
+            }),
+        ],
+        MockConnectionEnd::Hold,
+    )])
+    .await;
+    MockBybitServerOrderBooksL2::bind(&server.url);
+
+    let stream = init_market_stream(
+        TEST_RECONNECTION_POLICY,
+        vec![
+            Subscription::<Bybit<MockBybitServerOrderBooksL2>, _, _>::from((
+                Bybit::default(),
+                "btc",
+                "usdt",
+                MarketDataInstrumentKind::Perpetual,
+                OrderBooksL2,
+            )),
+        ],
+    )
+    .await
+    .unwrap();
+    let mut stream = std::pin::pin!(stream);
+
+    let snapshot = next_item(&mut stream).await;
+    assert!(
+        matches!(&snapshot.kind, OrderBookEvent::Snapshot(book) if book.sequence() == 1000),
+        "expected Snapshot, but received: {snapshot:?}"
+    );
+
+    let update = next_item(&mut stream).await;
+    assert!(
+        matches!(&update.kind, OrderBookEvent::Update(book) if book.sequence() == 1001),
+        "expected Update, but received: {update:?}"
+    );
+}
@@ -0,0 +1,239 @@
+//! Golden file tests for exchange [`ExchangeTransformer`]s.
+//!
+//! Each test feeds the recorded raw exchange frames in `tests/fixtures/<case>.jsonl` (one frame
+//! per line) through a freshly initialised transformer, and asserts the produced outputs against
+//! `tests/golden/<case>.json`.
+//!
+//! After an intentional change to a transformer's output, regenerate the golden files with:
+//! ```sh
+//! UPDATE_GOLDEN=1 cargo test -p barter-data --test transformer_golden
+//! ```
+//! and review the resulting diff before committing.
+
+use barter_data::{
+    error::DataError,
+    event::MarketEvent,
+    exchange::{
+        binance::{spot::BinanceSpot, trade::BinanceTrade},
+        bybit::{book::l2::BybitOrderBooksL2Transformer, futures::BybitPerpetualsUsd},
+        gateio::{book::l1::GateioOrderBookL1, spot::GateioSpot},
+        hyperliquid::{Hyperliquid, book::l2::HyperliquidOrderBookL2},
+        kucoin::{Kucoin, trade::KucoinTrade},
+        okx::{
+            Okx,
+            book::l1::OkxOrderBooksL1,
+            contract::{OkxContractTransformer, OkxContracts, OkxInstrumentsResponse},
+            liquidation::OkxLiquidationsTransformer,
+            trade::OkxTrades,
+        },
+    },
+    subscription::{
+        Map, SubscriptionKind,
+        book::{OrderBooksL1, OrderBooksL2},
+        trade::PublicTrades,
+    },
+    transformer::{ExchangeTransformer, stateless::StatelessTransformer},
+};
+use barter_integration::{Transformer, subscription::SubscriptionId};
+use serde::{Serialize, de::DeserializeOwned};
+use serde_json::{Value, json};
+use std::path::PathBuf;
+
+/// Environment variable that, when set, overwrites the golden files with the actual outputs.
+const ENV_UPDATE_GOLDEN: &str = "UPDATE_GOLDEN";
+
+/// Run the fixture frames of the provided `case` through the `Exchange` transformer `T`,
+/// initialised with the provided `(SubscriptionId, InstrumentKey)` pairs, and assert the outputs
+/// match the golden file.
+async fn assert_golden<Exchange, Kind, T>(case: &str, subscriptions: &[(&str, &str)])
+where
+    Kind: SubscriptionKind,
+    Kind::Event: Serialize,
+    T: ExchangeTransformer<Exchange, String, Kind>,
+    T::Input: DeserializeOwned,
+{
+    let (ws_sink_tx, _ws_sink_rx) = tokio::sync::mpsc::unbounded_channel();
+    let transformer = T::init(instrument_map(subscriptions), &[], ws_sink_tx)
+        .await
+        .unwrap_or_else(|error| panic!("{case}: failed to init transformer: {error}"));
+
+    assert_golden_transformer(case, transformer);
+}
+
+/// Construct the instrument [`Map`] of the provided `(SubscriptionId, InstrumentKey)` pairs.
+fn instrument_map(subscriptions: &[(&str, &str)]) -> Map<String> {
+    subscriptions
+        .iter()
+        .map(|(sub_id, key)| (SubscriptionId::from(*sub_id), key.to_string()))
+        .collect()
+}
+
+/// Construct the [`OkxContracts`] of the recorded Okx SWAP instruments response fixture.
+fn okx_contracts() -> OkxContracts {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("fixtures")
+        .join("okx_instruments_swap.json");
+
+    let response = std::fs::read_to_string(&path)
+        .unwrap_or_else(|error| panic!("failed to read {path:?}: {error}"));
+
+    serde_json::from_str::<OkxInstrumentsResponse>(&response)
+        .unwrap()
+        .data
+        .into_iter()
+        .filter_map(|spec| Some((spec.instrument.clone(), spec.contract()?)))
+        .collect()
+}
+
+/// Run the fixture frames of the provided `case` through the provided initialised transformer,
+/// and assert the outputs match the golden file.
+///
+/// Used for transformers that fetch data over HTTP on initialisation (eg/ Okx contract
+/// specifications), which are constructed from recorded data instead.
+fn assert_golden_transformer<T, Event>(case: &str, mut transformer: T)
+where
+    T: Transformer<Output = MarketEvent<String, Event>, Error = DataError>,
+    T::Input: DeserializeOwned,
+    Event: Serialize,
+{
+    let dir = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests");
+    let fixture_path = dir.join("fixtures").join(format!("{case}.jsonl"));
+    let golden_path = dir.join("golden").join(format!("{case}.json"));
+
+    let fixture = std::fs::read_to_string(&fixture_path)
+        .unwrap_or_else(|error| panic!("{case}: failed to read {fixture_path:?}: {error}"));
+
+    let actual = fixture
+        .lines()
+        .filter(|line| !line.trim().is_empty())
+        .enumerate()
+        .map(|(index, line)| {
+            let input = serde_json::from_str::<T::Input>(line).unwrap_or_else(|error| {
+                panic!("{case}: failed to deserialise fixture frame {index}: {error}")
+            });
+
+            transformer
+                .transform(input)
+                .into_iter()
+                .map(|output| match output {
+                    Ok(event) => {
+                        let mut event = serde_json::to_value(event).unwrap();
+                        // Set when the transformer runs, so it cannot be part of the golden file
+                        event.as_object_mut().unwrap().remove("time_received");
+                        event
+                    }
+                    Err(error) => json!({ "error": error.to_string() }),
+                })
+                .collect::<Value>()
+        })
+        .collect::<Value>();
+
+    if std::env::var_os(ENV_UPDATE_GOLDEN).is_some() {
+        let golden = serde_json::to_string_pretty(&actual).unwrap() + "\n";
+        std::fs::write(&golden_path, golden)
+            .unwrap_or_else(|error| panic!("{case}: failed to write {golden_path:?}: {error}"));
+        return;
+    }
+
+    let golden = std::fs::read_to_string(&golden_path).unwrap_or_else(|error| {
+        panic!("{case}: failed to read {golden_path:?} (run with {ENV_UPDATE_GOLDEN}=1): {error}")
+    });
+    let expected = serde_json::from_str::<Value>(&golden).unwrap();
+
+    assert_eq!(
+        actual,
+        expected,
+        "{case}: transformer output differs from golden file:\nActual:\n{}",
+        serde_json::to_string_pretty(&actual).unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_binance_spot_trades() {
+    assert_golden::<
+        BinanceSpot,
+        PublicTrades,
+        StatelessTransformer<BinanceSpot, String, PublicTrades, BinanceTrade>,
+    >("binance_spot_trades", &[("@trade|ETHUSDT", "eth_usdt")])
+    .await;
+}
+
+#[tokio::test]
+async fn test_okx_trades() {
+    assert_golden::<Okx, PublicTrades, OkxContractTransformer<String, PublicTrades, OkxTrades>>(
+        "okx_trades",
+        &[("trades|BTC-USDT", "btc_usdt")],
+    )
+    .await;
+}
+
+#[test]
+fn test_okx_order_books_l1() {
+    assert_golden_transformer(
+        "okx_order_books_l1",
+        OkxContractTransformer::<_, OrderBooksL1, OkxOrderBooksL1>::new(
+            instrument_map(&[("bbo-tbt|BCH-USDT-SWAP", "bch_usdt_perpetual")]),
+            okx_contracts(),
+        ),
+    );
+}
+
+#[test]
+fn test_okx_liquidations() {
+    assert_golden_transformer(
+        "okx_liquidations",
+        OkxLiquidationsTransformer::new(
+            instrument_map(&[
+                ("liquidation-orders|BTC-USDT-SWAP", "btc_usdt_perpetual"),
+                ("liquidation-orders|ETH-USDT-SWAP", "eth_usdt_perpetual"),
+            ]),
+            okx_contracts(),
+        ),
+    );
+}
+
+#[tokio::test]
+async fn test_bybit_perpetuals_order_books_l2() {
+    assert_golden::<BybitPerpetualsUsd, OrderBooksL2, BybitOrderBooksL2Transformer<String>>(
+        "bybit_perpetuals_order_books_l2",
+        &[("orderbook.50|BTCUSDT", "btc_usdt_perpetual")],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_gateio_spot_order_books_l1() {
+    assert_golden::<
+        GateioSpot,
+        OrderBooksL1,
+        StatelessTransformer<GateioSpot, String, OrderBooksL1, GateioOrderBookL1>,
+    >(
+        "gateio_spot_order_books_l1",
+        &[("spot.book_ticker|BTC_USDT", "btc_usdt")],
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_kucoin_trades() {
+    assert_golden::<
+        Kucoin,
+        PublicTrades,
+        StatelessTransformer<Kucoin, String, PublicTrades, KucoinTrade>,
+    >("kucoin_trades", &[("/market/match|BTC-USDT", "btc_usdt")])
+    .await;
+}
+
+#[tokio::test]
+async fn test_hyperliquid_order_books_l2() {
+    assert_golden::<
+        Hyperliquid,
+        OrderBooksL2,
+        StatelessTransformer<Hyperliquid, String, OrderBooksL2, HyperliquidOrderBookL2>,
+    >(
+        "hyperliquid_order_books_l2",
+        &[("l2Book|BTC", "btc_usdc_perpetual")],
+    )
+    .await;
+}
@@ -33,3 +33,25 @@ impl Encoder for Base64Encoder {
         base64::engine::general_purpose::STANDARD.encode(data)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use hmac::{Hmac, Mac};
+
+    #[test]
+    fn test_encode_hmac_sha256_signature() {
+        let mut mac = Hmac::<sha2::Sha256>::new_from_slice(b"key").unwrap();
+        mac.update(b"The quick brown fox jumps over the lazy dog");
+        let signature = mac.finalize().into_bytes();
+
+        assert_eq!(
+            HexEncoder.encode(signature),
+            "f7bc83f430538424b13298e6aa6fb143ef4d59a14946175997479dbc2d1a3cd8"
+        );
+        assert_eq!(
+            Base64Encoder.encode(signature),
+            "97yD9DBThCSxMpjmqm+xQ+9NWaFJRhdZl0edvC0aPNg="
+        );
+    }
+}
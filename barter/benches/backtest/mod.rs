@@ -161,7 +161,7 @@ const CONFIG: &str = r#"
 "#;
 
 const FILE_PATH_MARKET_DATA_INDEXED: &str =
-    "examples/data/binance_spot_trades_l1_btcusdt_ethusdt_solusdt.json";
+    "../examples/data/binance_spot_trades_l1_btcusdt_ethusdt_solusdt.json";
 
 #[derive(Deserialize)]
 pub struct Config {
@@ -398,21 +398,12 @@ impl
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 struct LoseMoneyInstrumentData {
     last_trade: Option<PublicTrade>,
     market_data: DefaultInstrumentMarketData,
 }
 
-impl Default for LoseMoneyInstrumentData {
-    fn default() -> Self {
-        Self {
-            last_trade: None,
-            market_data: DefaultInstrumentMarketData::default(),
-        }
-    }
-}
-
 impl InstrumentDataState for LoseMoneyInstrumentData {
     type MarketEventKind = DataKind;
 
@@ -464,7 +455,7 @@ fn args_constant(
     let time_engine_start = DateTime::<Utc>::from_str("2025-03-25T23:07:00.773674205Z").unwrap();
 
     // Construct EngineState
-    let engine_state = EngineStateBuilder::new(&instruments, DefaultGlobalData::default(), |_| {
+    let engine_state = EngineStateBuilder::new(&instruments, DefaultGlobalData, |_| {
         LoseMoneyInstrumentData::default()
     })
     .time_engine_start(time_engine_start)
@@ -62,7 +62,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         DefaultStrategy::default(),
         DefaultRiskManager::default(),
         market_stream,
-        DefaultGlobalData::default(),
+        DefaultGlobalData,
         |_| DefaultInstrumentMarketData::default(),
     );
 
@@ -19,7 +19,6 @@ use barter::{
     risk::DefaultRiskManager,
     statistic::{summary::instrument::TearSheetGenerator, time::Daily},
     strategy::{
-        DefaultStrategy,
         algo::AlgoStrategy,
         close_positions::{ClosePositionsStrategy, build_ioc_market_order_to_close_position},
         on_disconnect::OnDisconnectStrategy,
@@ -157,7 +156,7 @@ impl ClosePositionsStrategy for MultiStrategy {
                                 position_a,
                                 StrategyA::ID,
                                 price,
-                                || ClientOrderId::random(),
+                                ClientOrderId::random,
                             )
                         });
 
@@ -174,7 +173,7 @@ impl ClosePositionsStrategy for MultiStrategy {
                                 position_b,
                                 StrategyB::ID,
                                 price,
-                                || ClientOrderId::random(),
+                                ClientOrderId::random,
                             )
                         });
 
@@ -335,10 +334,13 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
         &instruments,
         executions,
         LiveClock,
-        DefaultStrategy::default(),
+        MultiStrategy {
+            strategy_a: StrategyA,
+            strategy_b: StrategyB,
+        },
         DefaultRiskManager::default(),
         market_stream,
-        DefaultGlobalData::default(),
+        DefaultGlobalData,
         |_| MultiStrategyCustomInstrumentData::init(Utc::now()),
     );
 
@@ -153,12 +153,15 @@ impl ConnectivityStates {
 /// Used to track both market data and account connections in a [`ConnectivityState`].
 ///
 /// Default implementation is [`Health::Reconnecting`].
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize)]
+#[derive(
+    Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash, Deserialize, Serialize, Default,
+)]
 pub enum Health {
     /// Connection is established and functioning normally.
     Healthy,
 
     /// Connection is currently attempting to re-establish after a disconnect or failure.
+    #[default]
     Reconnecting,
 }
 
@@ -183,12 +186,6 @@ impl ConnectivityState {
     }
 }
 
-impl Default for Health {
-    fn default() -> Self {
-        Self::Reconnecting
-    }
-}
-
 /// Generates an indexed [`ConnectivityStates`] containing default connection states.
 ///
 /// Creates a new connection state tracker for each exchange in the provided instruments, with all
@@ -1164,10 +1164,10 @@ mod tests {
         for (index, test) in cases.into_iter().enumerate() {
             let actual = calculate_pnl_realised(
                 test.side,
-                test.price_entry_average.into(),
-                test.closed_quantity.into(),
-                test.closed_price.into(),
-                test.closed_fee.into(),
+                test.price_entry_average,
+                test.closed_quantity,
+                test.closed_price,
+                test.closed_fee,
             );
 
             assert_eq!(actual, test.expected, "TC{} failed", index);
@@ -1216,9 +1216,9 @@ mod tests {
 
         for (index, test) in cases.into_iter().enumerate() {
             let actual = calculate_pnl_return(
-                test.pnl_realised.into(),
-                test.price_entry_average.into(),
-                test.quantity_abs_max.into(),
+                test.pnl_realised,
+                test.price_entry_average,
+                test.quantity_abs_max,
             );
 
             assert_eq!(actual, test.expected, "TC{} failed", index);
@@ -121,3 +121,36 @@ impl From<InstrumentConfig> for Instrument<ExchangeId, Asset> {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_de_example_system_config() {
+        let config = serde_json::from_str::<SystemConfig>(include_str!(
+            "../../examples/config/system_config.json"
+        ))
+        .unwrap();
+
+        let instruments = config
+            .instruments
+            .into_iter()
+            .map(Instrument::from)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            instruments
+                .iter()
+                .map(|instrument| instrument.name_exchange.name().as_str())
+                .collect::<Vec<_>>(),
+            vec!["BTCUSDT", "ETHUSDT", "SOLUSDT"]
+        );
+        assert!(
+            instruments
+                .iter()
+                .all(|instrument| instrument.exchange == ExchangeId::BinanceSpot)
+        );
+        assert_eq!(config.executions.len(), 1);
+    }
+}